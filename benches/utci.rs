@@ -0,0 +1,94 @@
+//! Throughput of the UTCI polynomial and
+//! of batch evaluation
+
+use chrono::{Duration, TimeZone, Utc};
+use criterion::{
+    criterion_group, criterion_main, BenchmarkId, Criterion, Throughput,
+};
+use std::hint::black_box;
+use utci::heat_indices::{utci_approx, utci_approx_slice};
+use utci::{experienced_heat_stress_batch, Location, ObservationColumns};
+
+// Inputs spread over the range of the polynomial
+fn inputs(len: usize) -> [Vec<f64>; 4] {
+    let spread = |index: usize, low: f64, high: f64, step: usize| {
+        low + (high - low) * ((index * step) % 1000) as f64 / 1000.0
+    };
+    [
+        (0..len).map(|i| spread(i, -20.0, 40.0, 7)).collect(),
+        (0..len).map(|i| spread(i, 0.5, 15.0, 13)).collect(),
+        (0..len).map(|i| spread(i, -10.0, 50.0, 17)).collect(),
+        (0..len).map(|i| spread(i, 0.2, 4.0, 19)).collect(),
+    ]
+}
+
+fn polynomial(c: &mut Criterion) {
+    c.bench_function("utci_approx", |b| {
+        b.iter(|| {
+            utci_approx(
+                black_box(25.0),
+                black_box(1.0),
+                black_box(5.0),
+                black_box(1.6),
+            )
+        })
+    });
+
+    let mut group = c.benchmark_group("utci_approx_slice");
+    for len in [1_000, 100_000, 1_000_000] {
+        let [ta, va, d_tmrt, pa] = inputs(len);
+        let mut output = vec![0.0; len];
+        group.throughput(Throughput::Elements(len as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(len),
+            &len,
+            |b, _| {
+                b.iter(|| {
+                    utci_approx_slice(&ta, &va, &d_tmrt, &pa, &mut output)
+                        .unwrap()
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+fn batch(c: &mut Criterion) {
+    // a day of 10-minute observations from 100 sensors
+    let sensors = 100;
+    let len = 144 * sensors;
+    let start = Utc.with_ymd_and_hms(2021, 7, 1, 0, 0, 0).unwrap();
+    let timestamp: Vec<_> = (0..len)
+        .map(|i| start + Duration::minutes(10 * (i / sensors) as i64))
+        .collect();
+    let air_temperature: Vec<f32> =
+        (0..len).map(|i| 15.0 + (i % 15) as f32).collect();
+    let wind_speed = vec![2.0; len];
+    let relative_humidity = vec![60.0; len];
+    let location: Vec<usize> = (0..len).map(|i| i % sensors).collect();
+    let locations: Vec<Location> = (0..sensors)
+        .map(|i| Location::groningen(i % 2 == 0, i % 3 == 0, i % 5 == 0))
+        .collect();
+    let columns = ObservationColumns {
+        air_temperature: &air_temperature,
+        wind_speed: &wind_speed,
+        relative_humidity: &relative_humidity,
+        timestamp: &timestamp,
+        location: &location,
+        global_radiation: &[],
+    };
+    let mut output = vec![0.0; len];
+
+    let mut group = c.benchmark_group("experienced_heat_stress_batch");
+    group.throughput(Throughput::Elements(len as u64));
+    group.bench_function("day of 100 sensors", |b| {
+        b.iter(|| {
+            experienced_heat_stress_batch(&columns, &locations, &mut output)
+                .unwrap()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, polynomial, batch);
+criterion_main!(benches);
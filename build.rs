@@ -0,0 +1,32 @@
+//! Generates the C header of the `ffi` feature
+
+fn main() {
+    #[cfg(feature = "ffi")]
+    generate_header();
+}
+
+// Function to write the declarations of the
+// C interface to OUT_DIR, copied to the path in
+// UTCI_HEADER when that is set
+#[cfg(feature = "ffi")]
+fn generate_header() {
+    println!("cargo:rerun-if-changed=src/ffi.rs");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+    println!("cargo:rerun-if-env-changed=UTCI_HEADER");
+
+    let config = cbindgen::Config::from_file("cbindgen.toml")
+        .expect("cbindgen.toml is readable");
+    let header = cbindgen::Builder::new()
+        .with_src("src/ffi.rs")
+        .with_config(config)
+        .generate()
+        .expect("the C interface can be declared");
+
+    let out_dir = std::env::var("OUT_DIR").expect("cargo sets OUT_DIR");
+    let path = std::path::Path::new(&out_dir).join("utci.h");
+    header.write_to_file(&path);
+    println!("cargo:rustc-env=UTCI_GENERATED_HEADER={}", path.display());
+    if let Some(destination) = std::env::var_os("UTCI_HEADER") {
+        header.write_to_file(destination);
+    }
+}
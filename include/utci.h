@@ -0,0 +1,220 @@
+#ifndef UTCI_H
+#define UTCI_H
+
+/* Generated by cbindgen from src/ffi.rs, do not edit */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Outcome of a call, with an error code for
+ * every variant of `Error`
+ */
+typedef enum UtciStatus {
+  /**
+   * Calculated without error
+   */
+  UtciOk = 0,
+  /**
+   * Invalid data as an input
+   */
+  UtciInvalidInput = 1,
+  /**
+   * Air temp out of range
+   */
+  UtciInvalidAirTemperature = 2,
+  /**
+   * Radiant temp out of range
+   */
+  UtciInvalidRadiantTemperature = 3,
+  /**
+   * Radiant flux out of range
+   */
+  UtciInvalidRadiantFlux = 4,
+  /**
+   * Humidity out of range
+   */
+  UtciInvalidHumidity = 5,
+  /**
+   * Wind Speed out of range
+   */
+  UtciInvalidWindSpeed = 6,
+  /**
+   * Canyon geometry is not physical
+   */
+  UtciInvalidCanyonGeometry = 7,
+  /**
+   * Latitude or longitude out of range
+   */
+  UtciInvalidCoordinates = 8,
+  /**
+   * Material properties out of range
+   */
+  UtciInvalidMaterial = 9,
+  /**
+   * Material file could not be read
+   */
+  UtciMaterialFile = 10,
+  /**
+   * A required pointer was null
+   */
+  UtciNullPointer = 11,
+  /**
+   * Timestamp could not be read
+   */
+  UtciInvalidTimestamp = 12,
+  /**
+   * Column missing from a CSV header
+   */
+  UtciMissingColumn = 13,
+  /**
+   * CSV file could not be read or written
+   */
+  UtciCsvFile = 14,
+  /**
+   * Value missing from a data file
+   */
+  UtciMissingValue = 15,
+  /**
+   * NetCDF file could not be read or written
+   */
+  UtciNetcdfFile = 16,
+  /**
+   * GeoTIFF file could not be read or written
+   */
+  UtciGeoTiffFile = 17,
+  /**
+   * Personal profile out of range
+   */
+  UtciInvalidProfile = 18,
+  /**
+   * Energy balance did not converge
+   */
+  UtciNoConvergence = 19,
+  /**
+   * Material missing from the library
+   */
+  UtciUnknownMaterial = 20,
+} UtciStatus;
+
+/**
+ * Observation of the weather and surroundings,
+ * with the moment in seconds since 1970 UTC
+ */
+typedef struct UtciObservation {
+  /**
+   * Air temperature [C]
+   */
+  float air_temperature;
+  /**
+   * Wind speed [m/s]
+   */
+  float wind_speed;
+  /**
+   * Relative humidity [%]
+   */
+  float relative_humidity;
+  /**
+   * Situated in the city centre
+   */
+  bool binnenstad;
+  /**
+   * Situated in a park
+   */
+  bool park;
+  /**
+   * Situated under the shade of a tree
+   */
+  bool shade;
+  /**
+   * Moment of the observation [s]
+   */
+  int64_t timestamp;
+} UtciObservation;
+
+/**
+ * Experienced Heat Stress of an observation
+ */
+typedef struct UtciHeatStress {
+  /**
+   * UTCI equivalent temperature [C]
+   */
+  float utci;
+  /**
+   * Mean radiant temperature [C]
+   */
+  float radiant_temperature;
+  /**
+   * Index of the stress category, from 0 for
+   * extreme cold to 9 for extreme heat stress
+   */
+  uint8_t category;
+} UtciHeatStress;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Calculates experienced Heat Stress in Groningen,
+ * writing the result into the output
+ *
+ * # Safety
+ *
+ * `output` must be null or point to writable
+ * memory for a `UtciHeatStress`.
+ */
+enum UtciStatus utci_experienced_heat_stress(struct UtciObservation observation,
+                                             struct UtciHeatStress *output);
+
+/**
+ * Calculates the UTCI of `len` observations at a
+ * single location in Groningen, with moments in
+ * seconds since 1970 UTC
+ *
+ * Observations outside of the supported ranges
+ * are written as NaN and counted in `invalid`.
+ *
+ * # Safety
+ *
+ * The input and output pointers must point to
+ * `len` values each. `invalid` must be null or
+ * point to writable memory for a `size_t`.
+ */
+enum UtciStatus utci_experienced_heat_stress_batch(const float *air_temperature,
+                                                   const float *wind_speed,
+                                                   const float *relative_humidity,
+                                                   const int64_t *timestamp,
+                                                   size_t len,
+                                                   bool binnenstad,
+                                                   bool park,
+                                                   bool shade,
+                                                   float *output,
+                                                   size_t *invalid);
+
+/**
+ * Index of the stress category of a UTCI value,
+ * from 0 for extreme cold to 9 for extreme heat
+ */
+uint8_t utci_stress_category(float utci);
+
+/**
+ * English name of a stress category as a static
+ * string, or null for an unknown index
+ */
+const char *utci_stress_category_name(uint8_t category);
+
+/**
+ * Description of a status code as a static
+ * string, or "Unknown status" for an unknown code
+ */
+const char *utci_status_message(int status);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* UTCI_H */
@@ -0,0 +1,178 @@
+//! Local HTTP service for calculating
+//! experienced Heat Stress
+
+use std::io::Read;
+use std::process;
+
+use chrono::{DateTime, Utc};
+use clap::Parser;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use utci::{
+    experienced_heat_stress, HeatStressOutput, ObservationInput, StressCategory,
+};
+
+// Largest request body that is read [bytes]
+const MAX_BODY_SIZE: u64 = 1 << 20;
+
+/// Serves experienced Heat Stress in Groningen
+///
+/// POST /utci takes a JSON observation, or an
+/// array of them, and GET /categories lists the
+/// UTCI assessment scale. Bodies over 1 MiB are
+/// refused with 413.
+#[derive(Parser, Debug)]
+#[command(name = "utci-server", version)]
+struct Arguments {
+    /// Address to listen on
+    #[arg(long, default_value = "127.0.0.1:8080")]
+    address: String,
+}
+
+/// Body of POST /utci
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum UtciRequest {
+    Single(ObservationInput),
+    Batch(Vec<ObservationInput>),
+}
+
+fn main() {
+    let arguments = Arguments::parse();
+    let server = match Server::http(&arguments.address) {
+        Ok(server) => server,
+        Err(error) => {
+            eprintln!("utci-server: {}", error);
+            process::exit(1);
+        }
+    };
+    eprintln!("utci-server: listening on http://{}", arguments.address);
+
+    for mut request in server.incoming_requests() {
+        let (status, value) = match read_body(&mut request) {
+            Ok(body) => route(request.method(), request.url(), &body),
+            Err(response) => response,
+        };
+        if let Err(error) = respond(request, status, &value) {
+            eprintln!("utci-server: {}", error);
+        }
+    }
+}
+
+// Function to read the body of a request, up to
+// MAX_BODY_SIZE bytes
+fn read_body(request: &mut Request) -> Result<String, (u16, Value)> {
+    let too_large = || (413, error_body("Request body is too large"));
+    let declared = request.body_length();
+    if declared.is_some_and(|length| length as u64 > MAX_BODY_SIZE) {
+        return Err(too_large());
+    }
+
+    let mut body = String::new();
+    request
+        .as_reader()
+        .take(MAX_BODY_SIZE + 1)
+        .read_to_string(&mut body)
+        .map_err(|error| (400, error_body(&error.to_string())))?;
+    if body.len() as u64 > MAX_BODY_SIZE {
+        return Err(too_large());
+    }
+    Ok(body)
+}
+
+// Function to find the response to a request
+fn route(method: &Method, url: &str, body: &str) -> (u16, Value) {
+    let path = url.split('?').next().unwrap_or(url);
+    match (method, path) {
+        (Method::Post, "/utci") => post_utci(body),
+        (Method::Get, "/categories") => (200, categories()),
+        (_, "/utci") | (_, "/categories") => {
+            (405, error_body("Method not allowed"))
+        }
+        _ => (404, error_body("Not found")),
+    }
+}
+
+// Function to calculate heat stress for a single
+// observation or a batch of them
+//
+// A single observation outside of the supported
+// ranges is answered with 400, a batch reports
+// the error in place of the failed observation.
+fn post_utci(body: &str) -> (u16, Value) {
+    let request: UtciRequest = match serde_json::from_str(body) {
+        Ok(request) => request,
+        Err(error) => return (400, error_body(&error.to_string())),
+    };
+    let now = Utc::now();
+
+    match request {
+        UtciRequest::Single(input) => match calculate(input, now) {
+            Ok(output) => (200, output),
+            Err(output) => (400, output),
+        },
+        UtciRequest::Batch(inputs) => {
+            let outputs: Vec<Value> = inputs
+                .into_iter()
+                .map(|input| match calculate(input, now) {
+                    Ok(output) | Err(output) => output,
+                })
+                .collect();
+            (200, Value::Array(outputs))
+        }
+    }
+}
+
+// Function to calculate heat stress for an
+// observation, giving the error as JSON
+fn calculate(
+    input: ObservationInput,
+    now: DateTime<Utc>,
+) -> Result<Value, Value> {
+    let heat_stress = experienced_heat_stress(input.observation(now))
+        .map_err(|error| error_body(&error.to_string()))?;
+    serde_json::to_value(HeatStressOutput::from(heat_stress))
+        .map_err(|error| error_body(&error.to_string()))
+}
+
+// Function to list the UTCI assessment scale
+fn categories() -> Value {
+    let bounds = StressCategory::THRESHOLDS;
+    let categories: Vec<Value> = StressCategory::ALL
+        .iter()
+        .enumerate()
+        .map(|(index, category)| {
+            json!({
+                "category": category,
+                "name": category.name(),
+                "dutch_name": category.dutch_name(),
+                "colour": category.colour(),
+                "lower": index.checked_sub(1).map(|lower| bounds[lower]),
+                "upper": bounds.get(index),
+            })
+        })
+        .collect();
+    Value::Array(categories)
+}
+
+// Function to describe an error as JSON
+fn error_body(message: &str) -> Value {
+    json!({ "error": message })
+}
+
+// Function to send a JSON response
+fn respond(
+    request: Request,
+    status: u16,
+    value: &Value,
+) -> std::io::Result<()> {
+    let content_type = "Content-Type: application/json"
+        .parse::<Header>()
+        .expect("static header is valid");
+    let response = Response::from_string(value.to_string())
+        .with_status_code(status)
+        .with_header(content_type);
+    request.respond(response)
+}
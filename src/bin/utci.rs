@@ -0,0 +1,187 @@
+//! Command-line interface for calculating
+//! experienced Heat Stress
+
+use std::io::{self, Write};
+use std::process;
+
+use chrono::{DateTime, Utc};
+use clap::Parser;
+use serde_json::json;
+
+use utci::{experienced_heat_stress, Error, HeatStress, Observation};
+
+/// Calculates experienced Heat Stress in Groningen
+///
+/// Without measurements as arguments, CSV rows of
+/// air_temperature,wind_speed,relative_humidity and
+/// optionally binnenstad,park,shade,timestamp are
+/// read from stdin. Rows which fail are printed as
+/// an error in their place.
+#[derive(Parser, Debug)]
+#[command(name = "utci", version)]
+struct Arguments {
+    /// Air temperature [C]
+    #[arg(allow_negative_numbers = true, requires = "wind_speed")]
+    air_temperature: Option<f32>,
+    /// Wind speed [m/s]
+    #[arg(requires = "relative_humidity")]
+    wind_speed: Option<f32>,
+    /// Relative humidity [%]
+    relative_humidity: Option<f32>,
+    /// Situated in the city centre
+    #[arg(long)]
+    binnenstad: bool,
+    /// Situated in a park
+    #[arg(long)]
+    park: bool,
+    /// Situated under the shade of a tree
+    #[arg(long)]
+    shade: bool,
+    /// Moment of the observation as RFC 3339,
+    /// defaults to now
+    #[arg(long)]
+    time: Option<DateTime<Utc>>,
+    /// Print JSON instead of text
+    #[arg(long)]
+    json: bool,
+}
+
+fn main() {
+    let arguments = Arguments::parse();
+    let timestamp = arguments.time.unwrap_or_else(Utc::now);
+
+    let observation = match (
+        arguments.air_temperature,
+        arguments.wind_speed,
+        arguments.relative_humidity,
+    ) {
+        (Some(air_temperature), Some(wind_speed), Some(relative_humidity)) => {
+            Observation {
+                air_temperature,
+                wind_speed,
+                relative_humidity,
+                binnenstad: arguments.binnenstad,
+                park: arguments.park,
+                shade: arguments.shade,
+                timestamp,
+            }
+        }
+        _ => {
+            let succeeded = read_rows(&arguments, timestamp);
+            process::exit(if succeeded { 0 } else { 1 });
+        }
+    };
+
+    let result = experienced_heat_stress(observation);
+    let mut stdout = io::stdout();
+    let _ = writeln!(stdout, "{}", format_result(&result, arguments.json));
+    if result.is_err() {
+        process::exit(1);
+    }
+}
+
+// Function to calculate heat stress for every
+// CSV row on stdin, returning whether all rows
+// were calculated
+fn read_rows(arguments: &Arguments, timestamp: DateTime<Utc>) -> bool {
+    let stdin = io::stdin();
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .trim(csv::Trim::All)
+        .from_reader(stdin.lock());
+    let mut stdout = io::stdout();
+    let mut succeeded = true;
+
+    for (number, record) in reader.records().enumerate() {
+        let result = match record {
+            Ok(record) => {
+                let fields: Vec<&str> = record.iter().collect();
+                if number == 0 && is_header(&fields) {
+                    continue;
+                }
+                parse_row(&fields, arguments, timestamp)
+                    .and_then(experienced_heat_stress)
+            }
+            Err(error) if error.is_io_error() => {
+                eprintln!("utci: {}", error);
+                return false;
+            }
+            Err(error) => Err(Error::CsvFile(error.to_string())),
+        };
+        succeeded &= result.is_ok();
+        if writeln!(stdout, "{}", format_result(&result, arguments.json))
+            .is_err()
+        {
+            return false;
+        }
+    }
+    succeeded
+}
+
+// Function to detect a header row
+fn is_header(fields: &[&str]) -> bool {
+    fields
+        .first()
+        .is_some_and(|field| field.parse::<f32>().is_err())
+}
+
+// Function to parse a CSV row into an observation,
+// taking missing columns from the arguments
+fn parse_row(
+    fields: &[&str],
+    arguments: &Arguments,
+    timestamp: DateTime<Utc>,
+) -> Result<Observation, Error> {
+    if fields.len() < 3 {
+        return Err(Error::InvalidInput);
+    }
+    let number =
+        |field: &str| field.parse::<f32>().map_err(|_| Error::InvalidInput);
+    let flag = |index: usize, default: bool| match fields.get(index) {
+        None | Some(&"") => Ok(default),
+        Some(field) => parse_flag(field),
+    };
+
+    Ok(Observation {
+        air_temperature: number(fields[0])?,
+        wind_speed: number(fields[1])?,
+        relative_humidity: number(fields[2])?,
+        binnenstad: flag(3, arguments.binnenstad)?,
+        park: flag(4, arguments.park)?,
+        shade: flag(5, arguments.shade)?,
+        timestamp: match fields.get(6) {
+            None | Some(&"") => timestamp,
+            Some(field) => {
+                field.parse().map_err(|_| Error::InvalidTimestamp)?
+            }
+        },
+    })
+}
+
+// Function to parse a boolean CSV column
+fn parse_flag(field: &str) -> Result<bool, Error> {
+    match field.to_lowercase().as_str() {
+        "1" | "true" | "yes" | "ja" => Ok(true),
+        "0" | "false" | "no" | "nee" => Ok(false),
+        _ => Err(Error::InvalidInput),
+    }
+}
+
+// Function to format a result as text or JSON
+fn format_result(result: &Result<HeatStress, Error>, json: bool) -> String {
+    match (result, json) {
+        (Ok(heat_stress), false) => {
+            format!("{:.1}\t{}", heat_stress.utci, heat_stress.category())
+        }
+        (Err(error), false) => format!("error\t{}", error),
+        (Ok(heat_stress), true) => json!({
+            "utci": heat_stress.utci,
+            "radiant_temperature": heat_stress.radiant_temperature,
+            "category": heat_stress.category().name(),
+            "colour": heat_stress.category().colour(),
+        })
+        .to_string(),
+        (Err(error), true) => json!({ "error": error.to_string() }).to_string(),
+    }
+}
@@ -0,0 +1,97 @@
+//! Module containing the geometry of the
+//! urban canyon surrounding an observation
+
+use crate::error::Error;
+use crate::solar::SolarPosition;
+
+/// Geometry of a street canyon, given in metres
+/// with the orientation of the street axis in
+/// degrees clockwise from north
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CanyonGeometry {
+    /// Height of the buildings lining the street [m]
+    pub building_height: f32,
+    /// Distance between the buildings [m]
+    pub street_width: f32,
+    /// Height of the trees within the street [m]
+    pub tree_height: f32,
+    /// Orientation of the street axis [degrees]
+    pub orientation: f32,
+}
+
+impl CanyonGeometry {
+    /// Streets of the city centre of Groningen
+    pub const GRONINGEN_BINNENSTAD: CanyonGeometry = CanyonGeometry {
+        building_height: 13.4,
+        street_width: 12.6,
+        tree_height: 8.3,
+        orientation: 0.0,
+    };
+
+    /// Streets and parks of Groningen outside
+    /// of the city centre
+    pub const GRONINGEN_RESIDENTIAL: CanyonGeometry = CanyonGeometry {
+        building_height: 9.2,
+        street_width: 12.8,
+        tree_height: 8.3,
+        orientation: 0.0,
+    };
+
+    /// Preset of Groningen for the city centre
+    /// or the rest of the city
+    pub fn groningen(binnenstad: bool) -> CanyonGeometry {
+        if binnenstad {
+            CanyonGeometry::GRONINGEN_BINNENSTAD
+        } else {
+            CanyonGeometry::GRONINGEN_RESIDENTIAL
+        }
+    }
+
+    /// Checks the dimensions describe a
+    /// physical street canyon
+    pub fn validate(&self) -> Result<(), Error> {
+        let valid = self.building_height > 0.0
+            && self.street_width > 0.0
+            && self.tree_height >= 0.0
+            && self.orientation.is_finite();
+        if !valid {
+            return Err(Error::InvalidCanyonGeometry);
+        }
+        Ok(())
+    }
+
+    /// Sky View Factor of the open canyon
+    pub fn sky_view_factor(&self) -> f32 {
+        let svf = self.building_height / (0.5 * self.street_width);
+        svf.atan().cos()
+    }
+
+    /// Sky View Factor of the canyon with
+    /// trees present
+    pub fn sky_view_factor_trees(&self) -> f32 {
+        let svf = self.building_height / (0.5 * self.street_width);
+        let svf_1 = self.tree_height / self.building_height;
+        let svf_2 = svf * svf_1;
+        svf_2.atan().cos()
+    }
+
+    /// Whether the sun reaches the middle of
+    /// the street over the buildings
+    pub fn is_sunlit(&self, position: &SolarPosition) -> bool {
+        if !position.is_daytime() {
+            return false;
+        }
+        let angle = (position.azimuth - f64::from(self.orientation))
+            .to_radians()
+            .sin()
+            .abs();
+        // sunlight along the street axis is never blocked
+        if angle < 1e-6 {
+            return true;
+        }
+        let distance = 0.5 * f64::from(self.street_width) / angle;
+        let blocking_elevation =
+            (f64::from(self.building_height) / distance).atan();
+        position.elevation().to_radians() > blocking_elevation
+    }
+}
@@ -3,8 +3,9 @@
 use std::io;
 use thiserror::Error;
 
-#[derive(Error, Debug)]
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
 /// Standard error for the interface
+#[allow(clippy::enum_variant_names)]
 pub enum Error {
     /// Invalid data as an input
     #[error("Data given is not in floating point form, try adding a decimal point")]
@@ -13,20 +14,60 @@ pub enum Error {
     #[error("Air Temperature is outside of the range -50 < 0 < 50")]
     InvalidAirTemperature,
     /// Radiant temp out of range input
-    #[error("Radiant Temperature is outside of the range -50 < 0 < 50")]
+    #[error("Radiant Temperature is outside of Air Temperature -30 < 0 < 70")]
     InvalidRadiantTemperature,
+    /// Radiant flux out of range input
+    #[error("Radiant flux is outside of the range 0 < 1400 W/m2")]
+    InvalidRadiantFlux,
     /// Humidity out of range input
     #[error("Humidity is out of range (0, 100)")]
     InvalidHumidity,
     /// Wind Speed out of range input
     #[error("Windspeed is out of range (0, 17)")]
-    InvalidWindSpeed
+    InvalidWindSpeed,
+    /// Canyon geometry is not physical
+    #[error("Canyon geometry needs a positive height and street width")]
+    InvalidCanyonGeometry,
+    /// Latitude or longitude out of range
+    #[error("Coordinates are outside of (-90, 90) and (-180, 180)")]
+    InvalidCoordinates,
+    /// Material properties out of range
+    #[error("Emissivity and albedo are out of range (0, 1)")]
+    InvalidMaterial,
+    /// Material file could not be read
+    #[error("Material file could not be read: {0}")]
+    MaterialFile(String),
+    /// Timestamp could not be read
+    #[error("Timestamp is not in the expected format")]
+    InvalidTimestamp,
+    /// Column missing from a CSV header
+    #[error("Column {0} is missing from the CSV header")]
+    MissingColumn(String),
+    /// Value missing from a data file
+    #[error("Value of {0} is missing")]
+    MissingValue(String),
+    /// CSV file could not be read or written
+    #[error("CSV file could not be processed: {0}")]
+    CsvFile(String),
+    /// NetCDF file could not be read or written
+    #[error("NetCDF file could not be processed: {0}")]
+    NetcdfFile(String),
+    /// GeoTIFF file could not be read or written
+    #[error("GeoTIFF file could not be processed: {0}")]
+    GeoTiffFile(String),
+    /// Personal profile out of range
+    #[error("Personal profile is outside of the range of its clothing, activity or body")]
+    InvalidProfile,
+    /// Energy balance did not converge
+    #[error("Energy balance did not converge")]
+    NoConvergence,
+    /// Material missing from the library
+    #[error("Material {0} is not in the library")]
+    UnknownMaterial(String),
 }
 
-impl Into<io::Error> for Error {
-    fn into(self) -> io::Error {
-        match self {
-            _ => io::Error::new(io::ErrorKind::Other, format!("{}", self)),
-        }
+impl From<Error> for io::Error {
+    fn from(error: Error) -> io::Error {
+        io::Error::other(error.to_string())
     }
-}
\ No newline at end of file
+}
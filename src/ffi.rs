@@ -0,0 +1,396 @@
+//! Module containing the C interface, declared
+//! in the generated header `include/utci.h`
+
+use std::convert::TryFrom;
+use std::ffi::CString;
+use std::os::raw::{c_char, c_int};
+use std::sync::OnceLock;
+use std::{ptr, slice};
+
+use chrono::{DateTime, TimeZone, Utc};
+
+use crate::error::Error;
+use crate::heat_indices::{
+    self, Location, Observation, ObservationColumns, StressCategory,
+};
+
+/// Outcome of a call, with an error code for
+/// every variant of `Error`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UtciStatus {
+    /// Calculated without error
+    UtciOk = 0,
+    /// Invalid data as an input
+    UtciInvalidInput = 1,
+    /// Air temp out of range
+    UtciInvalidAirTemperature = 2,
+    /// Radiant temp out of range
+    UtciInvalidRadiantTemperature = 3,
+    /// Radiant flux out of range
+    UtciInvalidRadiantFlux = 4,
+    /// Humidity out of range
+    UtciInvalidHumidity = 5,
+    /// Wind Speed out of range
+    UtciInvalidWindSpeed = 6,
+    /// Canyon geometry is not physical
+    UtciInvalidCanyonGeometry = 7,
+    /// Latitude or longitude out of range
+    UtciInvalidCoordinates = 8,
+    /// Material properties out of range
+    UtciInvalidMaterial = 9,
+    /// Material file could not be read
+    UtciMaterialFile = 10,
+    /// A required pointer was null
+    UtciNullPointer = 11,
+    /// Timestamp could not be read
+    UtciInvalidTimestamp = 12,
+    /// Column missing from a CSV header
+    UtciMissingColumn = 13,
+    /// CSV file could not be read or written
+    UtciCsvFile = 14,
+    /// Value missing from a data file
+    UtciMissingValue = 15,
+    /// NetCDF file could not be read or written
+    UtciNetcdfFile = 16,
+    /// GeoTIFF file could not be read or written
+    UtciGeoTiffFile = 17,
+    /// Personal profile out of range
+    UtciInvalidProfile = 18,
+    /// Energy balance did not converge
+    UtciNoConvergence = 19,
+    /// Material missing from the library
+    UtciUnknownMaterial = 20,
+}
+
+impl UtciStatus {
+    // Every status, in the order of their codes
+    const ALL: [UtciStatus; 21] = [
+        UtciStatus::UtciOk,
+        UtciStatus::UtciInvalidInput,
+        UtciStatus::UtciInvalidAirTemperature,
+        UtciStatus::UtciInvalidRadiantTemperature,
+        UtciStatus::UtciInvalidRadiantFlux,
+        UtciStatus::UtciInvalidHumidity,
+        UtciStatus::UtciInvalidWindSpeed,
+        UtciStatus::UtciInvalidCanyonGeometry,
+        UtciStatus::UtciInvalidCoordinates,
+        UtciStatus::UtciInvalidMaterial,
+        UtciStatus::UtciMaterialFile,
+        UtciStatus::UtciNullPointer,
+        UtciStatus::UtciInvalidTimestamp,
+        UtciStatus::UtciMissingColumn,
+        UtciStatus::UtciCsvFile,
+        UtciStatus::UtciMissingValue,
+        UtciStatus::UtciNetcdfFile,
+        UtciStatus::UtciGeoTiffFile,
+        UtciStatus::UtciInvalidProfile,
+        UtciStatus::UtciNoConvergence,
+        UtciStatus::UtciUnknownMaterial,
+    ];
+
+    // Function to find the error reported
+    // by a status
+    fn error(self) -> Option<Error> {
+        let error = match self {
+            UtciStatus::UtciOk | UtciStatus::UtciNullPointer => return None,
+            UtciStatus::UtciInvalidInput => Error::InvalidInput,
+            UtciStatus::UtciInvalidAirTemperature => {
+                Error::InvalidAirTemperature
+            }
+            UtciStatus::UtciInvalidRadiantTemperature => {
+                Error::InvalidRadiantTemperature
+            }
+            UtciStatus::UtciInvalidRadiantFlux => Error::InvalidRadiantFlux,
+            UtciStatus::UtciInvalidHumidity => Error::InvalidHumidity,
+            UtciStatus::UtciInvalidWindSpeed => Error::InvalidWindSpeed,
+            UtciStatus::UtciInvalidCanyonGeometry => {
+                Error::InvalidCanyonGeometry
+            }
+            UtciStatus::UtciInvalidCoordinates => Error::InvalidCoordinates,
+            UtciStatus::UtciInvalidMaterial => Error::InvalidMaterial,
+            UtciStatus::UtciMaterialFile => Error::MaterialFile(String::new()),
+            UtciStatus::UtciInvalidTimestamp => Error::InvalidTimestamp,
+            UtciStatus::UtciMissingColumn => {
+                Error::MissingColumn(String::new())
+            }
+            UtciStatus::UtciCsvFile => Error::CsvFile(String::new()),
+            UtciStatus::UtciMissingValue => Error::MissingValue(String::new()),
+            UtciStatus::UtciNetcdfFile => Error::NetcdfFile(String::new()),
+            UtciStatus::UtciGeoTiffFile => Error::GeoTiffFile(String::new()),
+            UtciStatus::UtciInvalidProfile => Error::InvalidProfile,
+            UtciStatus::UtciNoConvergence => Error::NoConvergence,
+            UtciStatus::UtciUnknownMaterial => {
+                Error::UnknownMaterial(String::new())
+            }
+        };
+        Some(error)
+    }
+}
+
+impl From<&Error> for UtciStatus {
+    fn from(error: &Error) -> UtciStatus {
+        match error {
+            Error::InvalidInput => UtciStatus::UtciInvalidInput,
+            Error::InvalidAirTemperature => {
+                UtciStatus::UtciInvalidAirTemperature
+            }
+            Error::InvalidRadiantTemperature => {
+                UtciStatus::UtciInvalidRadiantTemperature
+            }
+            Error::InvalidRadiantFlux => UtciStatus::UtciInvalidRadiantFlux,
+            Error::InvalidHumidity => UtciStatus::UtciInvalidHumidity,
+            Error::InvalidWindSpeed => UtciStatus::UtciInvalidWindSpeed,
+            Error::InvalidCanyonGeometry => {
+                UtciStatus::UtciInvalidCanyonGeometry
+            }
+            Error::InvalidCoordinates => UtciStatus::UtciInvalidCoordinates,
+            Error::InvalidMaterial => UtciStatus::UtciInvalidMaterial,
+            Error::MaterialFile(_) => UtciStatus::UtciMaterialFile,
+            Error::InvalidTimestamp => UtciStatus::UtciInvalidTimestamp,
+            Error::MissingColumn(_) => UtciStatus::UtciMissingColumn,
+            Error::CsvFile(_) => UtciStatus::UtciCsvFile,
+            Error::MissingValue(_) => UtciStatus::UtciMissingValue,
+            Error::NetcdfFile(_) => UtciStatus::UtciNetcdfFile,
+            Error::GeoTiffFile(_) => UtciStatus::UtciGeoTiffFile,
+            Error::InvalidProfile => UtciStatus::UtciInvalidProfile,
+            Error::NoConvergence => UtciStatus::UtciNoConvergence,
+            Error::UnknownMaterial(_) => UtciStatus::UtciUnknownMaterial,
+        }
+    }
+}
+
+/// Observation of the weather and surroundings,
+/// with the moment in seconds since 1970 UTC
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct UtciObservation {
+    /// Air temperature [C]
+    pub air_temperature: f32,
+    /// Wind speed [m/s]
+    pub wind_speed: f32,
+    /// Relative humidity [%]
+    pub relative_humidity: f32,
+    /// Situated in the city centre
+    pub binnenstad: bool,
+    /// Situated in a park
+    pub park: bool,
+    /// Situated under the shade of a tree
+    pub shade: bool,
+    /// Moment of the observation [s]
+    pub timestamp: i64,
+}
+
+/// Experienced Heat Stress of an observation
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UtciHeatStress {
+    /// UTCI equivalent temperature [C]
+    pub utci: f32,
+    /// Mean radiant temperature [C]
+    pub radiant_temperature: f32,
+    /// Index of the stress category, from 0 for
+    /// extreme cold to 9 for extreme heat stress
+    pub category: u8,
+}
+
+/// Calculates experienced Heat Stress in Groningen,
+/// writing the result into the output
+///
+/// # Safety
+///
+/// `output` must be null or point to writable
+/// memory for a `UtciHeatStress`.
+#[no_mangle]
+pub unsafe extern "C" fn utci_experienced_heat_stress(
+    observation: UtciObservation,
+    output: *mut UtciHeatStress,
+) -> UtciStatus {
+    if output.is_null() {
+        return UtciStatus::UtciNullPointer;
+    }
+    let timestamp = match timestamp(observation.timestamp) {
+        Ok(timestamp) => timestamp,
+        Err(error) => return UtciStatus::from(&error),
+    };
+
+    let result = heat_indices::experienced_heat_stress(Observation {
+        air_temperature: observation.air_temperature,
+        wind_speed: observation.wind_speed,
+        relative_humidity: observation.relative_humidity,
+        binnenstad: observation.binnenstad,
+        park: observation.park,
+        shade: observation.shade,
+        timestamp,
+    });
+    match result {
+        Ok(heat_stress) => {
+            *output = UtciHeatStress {
+                utci: heat_stress.utci,
+                radiant_temperature: heat_stress.radiant_temperature,
+                category: heat_stress.category() as u8,
+            };
+            UtciStatus::UtciOk
+        }
+        Err(error) => UtciStatus::from(&error),
+    }
+}
+
+/// Calculates the UTCI of `len` observations at a
+/// single location in Groningen, with moments in
+/// seconds since 1970 UTC
+///
+/// Observations outside of the supported ranges
+/// are written as NaN and counted in `invalid`.
+///
+/// # Safety
+///
+/// The input and output pointers must point to
+/// `len` values each. `invalid` must be null or
+/// point to writable memory for a `size_t`.
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn utci_experienced_heat_stress_batch(
+    air_temperature: *const f32,
+    wind_speed: *const f32,
+    relative_humidity: *const f32,
+    timestamp: *const i64,
+    len: usize,
+    binnenstad: bool,
+    park: bool,
+    shade: bool,
+    output: *mut f32,
+    invalid: *mut usize,
+) -> UtciStatus {
+    if !invalid.is_null() {
+        *invalid = 0;
+    }
+    if len == 0 {
+        return UtciStatus::UtciOk;
+    }
+    if air_temperature.is_null()
+        || wind_speed.is_null()
+        || relative_humidity.is_null()
+        || timestamp.is_null()
+        || output.is_null()
+    {
+        return UtciStatus::UtciNullPointer;
+    }
+
+    let timestamps = match slice::from_raw_parts(timestamp, len)
+        .iter()
+        .map(|seconds| self::timestamp(*seconds))
+        .collect::<Result<Vec<_>, Error>>()
+    {
+        Ok(timestamps) => timestamps,
+        Err(error) => return UtciStatus::from(&error),
+    };
+    let columns = ObservationColumns {
+        air_temperature: slice::from_raw_parts(air_temperature, len),
+        wind_speed: slice::from_raw_parts(wind_speed, len),
+        relative_humidity: slice::from_raw_parts(relative_humidity, len),
+        timestamp: &timestamps,
+        location: &[],
+        global_radiation: &[],
+    };
+    let location = Location::groningen(binnenstad, park, shade);
+    let output = slice::from_raw_parts_mut(output, len);
+
+    match heat_indices::experienced_heat_stress_batch(
+        &columns,
+        &[location],
+        output,
+    ) {
+        Ok(count) => {
+            if !invalid.is_null() {
+                *invalid = count;
+            }
+            UtciStatus::UtciOk
+        }
+        Err(error) => UtciStatus::from(&error),
+    }
+}
+
+/// Index of the stress category of a UTCI value,
+/// from 0 for extreme cold to 9 for extreme heat
+#[no_mangle]
+pub extern "C" fn utci_stress_category(utci: f32) -> u8 {
+    StressCategory::from_utci(utci) as u8
+}
+
+/// English name of a stress category as a static
+/// string, or null for an unknown index
+#[no_mangle]
+pub extern "C" fn utci_stress_category_name(category: u8) -> *const c_char {
+    static NAMES: OnceLock<Vec<CString>> = OnceLock::new();
+    let names = NAMES.get_or_init(|| {
+        StressCategory::ALL
+            .iter()
+            .map(|category| c_string(category.name()))
+            .collect()
+    });
+    match names.get(usize::from(category)) {
+        Some(name) => name.as_ptr(),
+        None => ptr::null(),
+    }
+}
+
+/// Description of a status code as a static
+/// string, or "Unknown status" for an unknown code
+#[no_mangle]
+pub extern "C" fn utci_status_message(status: c_int) -> *const c_char {
+    static UNKNOWN: &[u8] = b"Unknown status\0";
+    static MESSAGES: OnceLock<Vec<CString>> = OnceLock::new();
+    let messages = MESSAGES.get_or_init(|| {
+        UtciStatus::ALL
+            .iter()
+            .map(|status| match status.error() {
+                Some(Error::MaterialFile(_)) => {
+                    c_string("Material file could not be read")
+                }
+                Some(Error::MissingColumn(_)) => {
+                    c_string("Column is missing from the CSV header")
+                }
+                Some(Error::MissingValue(_)) => {
+                    c_string("Value is missing from the data file")
+                }
+                Some(Error::CsvFile(_)) => {
+                    c_string("CSV file could not be processed")
+                }
+                Some(Error::NetcdfFile(_)) => {
+                    c_string("NetCDF file could not be processed")
+                }
+                Some(Error::GeoTiffFile(_)) => {
+                    c_string("GeoTIFF file could not be processed")
+                }
+                Some(Error::UnknownMaterial(_)) => {
+                    c_string("Material is not in the library")
+                }
+                Some(error) => c_string(&error.to_string()),
+                None if *status == UtciStatus::UtciOk => {
+                    c_string("Calculated without error")
+                }
+                None => c_string("A required pointer was null"),
+            })
+            .collect()
+    });
+    let code = usize::try_from(status).ok();
+    match code.and_then(|code| messages.get(code)) {
+        Some(message) => message.as_ptr(),
+        None => UNKNOWN.as_ptr().cast(),
+    }
+}
+
+// Function to convert seconds since 1970
+// into a timestamp
+fn timestamp(seconds: i64) -> Result<DateTime<Utc>, Error> {
+    Utc.timestamp_opt(seconds, 0)
+        .single()
+        .ok_or(Error::InvalidTimestamp)
+}
+
+// Function to convert a message into a
+// nul terminated string
+fn c_string(message: &str) -> CString {
+    CString::new(message).unwrap_or_default()
+}
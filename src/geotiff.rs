@@ -0,0 +1,256 @@
+//! Module containing the reading and writing
+//! of single band GeoTIFF rasters
+
+use std::convert::TryFrom;
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
+
+use tiff::decoder::{Decoder, DecodingResult};
+use tiff::encoder::colortype::{ColorType, Gray32Float, Gray8};
+use tiff::encoder::{TiffEncoder, TiffValue};
+use tiff::tags::Tag;
+
+use crate::error::Error;
+use crate::heat_map::{heat_stress_map, HeatMap, HeatMapSettings, Weather};
+use crate::raster::{GeoKeys, GeoTransform, Raster};
+
+/// Method for reading the first band of a
+/// GeoTIFF as 32 bit floats
+///
+/// The placement is read from the pixel scale
+/// and tie point tags, and the GeoKeys are kept
+/// for writing derived rasters. Pixels equal to
+/// the GDAL no data value are read as NaN.
+pub fn read_geotiff<P: AsRef<Path>>(path: P) -> Result<Raster<f32>, Error> {
+    let file = File::open(path).map_err(geotiff_error)?;
+    let mut decoder =
+        Decoder::new(BufReader::new(file)).map_err(geotiff_error)?;
+    let (width, height) = decoder.dimensions().map_err(geotiff_error)?;
+    let (width, height) = (width as usize, height as usize);
+
+    let scale = find_tag(&mut decoder, Tag::ModelPixelScaleTag)?
+        .map(|value| value.into_f64_vec())
+        .transpose()
+        .map_err(geotiff_error)?;
+    let tiepoint = find_tag(&mut decoder, Tag::ModelTiepointTag)?
+        .map(|value| value.into_f64_vec())
+        .transpose()
+        .map_err(geotiff_error)?;
+    let transform = match (scale, tiepoint) {
+        (Some(scale), Some(tiepoint))
+            if scale.len() >= 2 && tiepoint.len() >= 6 =>
+        {
+            GeoTransform {
+                origin_x: tiepoint[3] - tiepoint[0] * scale[0],
+                origin_y: tiepoint[4] + tiepoint[1] * scale[1],
+                pixel_width: scale[0],
+                pixel_height: scale[1],
+            }
+        }
+        _ => GeoTransform::default(),
+    };
+
+    let geo_keys = GeoKeys {
+        directory: find_tag(&mut decoder, Tag::GeoKeyDirectoryTag)?
+            .map(|value| value.into_u16_vec())
+            .transpose()
+            .map_err(geotiff_error)?
+            .unwrap_or_default(),
+        doubles: find_tag(&mut decoder, Tag::GeoDoubleParamsTag)?
+            .map(|value| value.into_f64_vec())
+            .transpose()
+            .map_err(geotiff_error)?
+            .unwrap_or_default(),
+        ascii: find_tag(&mut decoder, Tag::GeoAsciiParamsTag)?
+            .map(|value| value.into_string())
+            .transpose()
+            .map_err(geotiff_error)?
+            .unwrap_or_default(),
+    };
+    let no_data = find_tag(&mut decoder, Tag::GdalNodata)?
+        .and_then(|value| value.into_string().ok())
+        .and_then(|text| {
+            text.trim_end_matches('\0').trim().parse::<f32>().ok()
+        });
+
+    let values: Vec<f32> = match decoder.read_image().map_err(geotiff_error)? {
+        DecodingResult::U8(values) => {
+            values.into_iter().map(|value| value as f32).collect()
+        }
+        DecodingResult::U16(values) => {
+            values.into_iter().map(|value| value as f32).collect()
+        }
+        DecodingResult::U32(values) => {
+            values.into_iter().map(|value| value as f32).collect()
+        }
+        DecodingResult::U64(values) => {
+            values.into_iter().map(|value| value as f32).collect()
+        }
+        DecodingResult::F16(values) => {
+            values.into_iter().map(|value| value.to_f32()).collect()
+        }
+        DecodingResult::F32(values) => values,
+        DecodingResult::F64(values) => {
+            values.into_iter().map(|value| value as f32).collect()
+        }
+        DecodingResult::I8(values) => {
+            values.into_iter().map(|value| value as f32).collect()
+        }
+        DecodingResult::I16(values) => {
+            values.into_iter().map(|value| value as f32).collect()
+        }
+        DecodingResult::I32(values) => {
+            values.into_iter().map(|value| value as f32).collect()
+        }
+        DecodingResult::I64(values) => {
+            values.into_iter().map(|value| value as f32).collect()
+        }
+    };
+    if values.len() != width * height {
+        return Err(Error::GeoTiffFile(String::from(
+            "only single band rasters are supported",
+        )));
+    }
+    let values = match no_data {
+        Some(no_data) => values
+            .into_iter()
+            .map(|value| if value == no_data { f32::NAN } else { value })
+            .collect(),
+        None => values,
+    };
+
+    let mut raster = Raster::from_vec(width, height, values, transform)?;
+    raster.geo_keys = geo_keys;
+    Ok(raster)
+}
+
+/// Method for writing a raster of 32 bit floats
+/// as a GeoTIFF, with NaN as no data
+pub fn write_geotiff<P: AsRef<Path>>(
+    path: P,
+    raster: &Raster<f32>,
+) -> Result<(), Error> {
+    write_raster::<Gray32Float, _>(path, raster, Some("nan"))
+}
+
+/// Method for writing a raster of codes, such
+/// as shading or surface types, as a GeoTIFF
+pub fn write_geotiff_u8<P: AsRef<Path>>(
+    path: P,
+    raster: &Raster<u8>,
+) -> Result<(), Error> {
+    write_raster::<Gray8, _>(path, raster, None)
+}
+
+/// Method for writing every raster of a heat
+/// stress map into a directory, as `utci.tif`,
+/// `sky_view_factor.tif`, `shading.tif` and
+/// `surface.tif`
+pub fn write_heat_map<P: AsRef<Path>>(
+    directory: P,
+    heat_map: &HeatMap,
+) -> Result<(), Error> {
+    let directory = directory.as_ref();
+    write_geotiff(directory.join("utci.tif"), &heat_map.utci)?;
+    write_geotiff(
+        directory.join("sky_view_factor.tif"),
+        &heat_map.sky_view_factor,
+    )?;
+    write_geotiff_u8(directory.join("shading.tif"), &heat_map.shading)?;
+    write_geotiff_u8(directory.join("surface.tif"), &heat_map.surface)
+}
+
+/// Method for mapping experienced Heat Stress
+/// from a land cover and height GeoTIFF,
+/// writing the rasters into a directory
+pub fn process_geotiff<P: AsRef<Path>, Q: AsRef<Path>, D: AsRef<Path>>(
+    land_cover: P,
+    heights: Q,
+    directory: D,
+    weather: &Weather,
+    settings: &HeatMapSettings,
+) -> Result<HeatMap, Error> {
+    let land_cover = read_geotiff(land_cover)?.map(|class| {
+        if (0.0..=255.0).contains(&class) {
+            class.round() as u8
+        } else {
+            0
+        }
+    });
+    let heights = read_geotiff(heights)?;
+    let heat_map = heat_stress_map(&land_cover, &heights, weather, settings)?;
+    write_heat_map(directory, &heat_map)?;
+    Ok(heat_map)
+}
+
+// Function to write a raster with the tags
+// placing it on the ground
+fn write_raster<C, P>(
+    path: P,
+    raster: &Raster<C::Inner>,
+    no_data: Option<&str>,
+) -> Result<(), Error>
+where
+    C: ColorType,
+    C::Inner: Copy,
+    [C::Inner]: TiffValue,
+    P: AsRef<Path>,
+{
+    let width =
+        u32::try_from(raster.width()).map_err(|_| Error::InvalidInput)?;
+    let height =
+        u32::try_from(raster.height()).map_err(|_| Error::InvalidInput)?;
+    let file = File::create(path).map_err(geotiff_error)?;
+    let mut encoder =
+        TiffEncoder::new(BufWriter::new(file)).map_err(geotiff_error)?;
+    let mut image = encoder
+        .new_image::<C>(width, height)
+        .map_err(geotiff_error)?;
+
+    let transform = &raster.transform;
+    let tags = image.encoder();
+    tags.write_tag(
+        Tag::ModelPixelScaleTag,
+        &[transform.pixel_width, transform.pixel_height, 0.0][..],
+    )
+    .map_err(geotiff_error)?;
+    tags.write_tag(
+        Tag::ModelTiepointTag,
+        &[0.0, 0.0, 0.0, transform.origin_x, transform.origin_y, 0.0][..],
+    )
+    .map_err(geotiff_error)?;
+    let geo_keys = &raster.geo_keys;
+    if !geo_keys.directory.is_empty() {
+        tags.write_tag(Tag::GeoKeyDirectoryTag, &geo_keys.directory[..])
+            .map_err(geotiff_error)?;
+    }
+    if !geo_keys.doubles.is_empty() {
+        tags.write_tag(Tag::GeoDoubleParamsTag, &geo_keys.doubles[..])
+            .map_err(geotiff_error)?;
+    }
+    if !geo_keys.ascii.is_empty() {
+        tags.write_tag(Tag::GeoAsciiParamsTag, geo_keys.ascii.as_str())
+            .map_err(geotiff_error)?;
+    }
+    if let Some(no_data) = no_data {
+        tags.write_tag(Tag::GdalNodata, no_data)
+            .map_err(geotiff_error)?;
+    }
+
+    image.write_data(raster.data()).map_err(geotiff_error)
+}
+
+// Function to look up an optional tag
+fn find_tag<R: std::io::Read + std::io::Seek>(
+    decoder: &mut Decoder<R>,
+    tag: Tag,
+) -> Result<Option<tiff::decoder::ifd::Value>, Error> {
+    decoder.find_tag(tag).map_err(geotiff_error)
+}
+
+// Function to describe an error of reading
+// or writing a GeoTIFF
+fn geotiff_error<E: std::fmt::Display>(error: E) -> Error {
+    Error::GeoTiffFile(error.to_string())
+}
@@ -0,0 +1,595 @@
+//! Module containing the reading of gridded
+//! weather from NetCDF, such as HARMONIE or ERA5
+//! extracts, and the writing of UTCI grids
+//! following the CF conventions
+
+use std::convert::TryFrom;
+use std::path::Path;
+
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use netcdf::{AttributeValue, Variable};
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+use crate::heat_indices::{
+    experienced_heat_stress_batch, Location, ObservationColumns, StressCategory,
+};
+use crate::series::{HumidityUnit, TemperatureUnit, WindSpeedUnit};
+use crate::site::Site;
+
+// Value written for cells outside of the
+// supported ranges
+const FILL_VALUE: f32 = -9999.0;
+
+// Category written for cells outside of the
+// supported ranges
+const CATEGORY_FILL_VALUE: u8 = u8::MAX;
+
+/// Names and units of the variables of a
+/// gridded NetCDF file, with the kind of
+/// location every cell is taken to be
+///
+/// The weather is laid out over the time, y
+/// and x dimensions. Coordinates are read as
+/// the axes of a regular grid, or per cell
+/// for a curvilinear one.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GridMapping {
+    /// Variable holding the time steps
+    pub time: String,
+    /// Variable holding the latitudes
+    pub latitude: String,
+    /// Variable holding the longitudes
+    pub longitude: String,
+    /// Variable holding the air temperature
+    pub air_temperature: String,
+    /// Variable holding the wind speed
+    pub wind_speed: String,
+    /// Variable holding the relative humidity
+    pub relative_humidity: String,
+    /// Variable holding the global radiation,
+    /// or a cloudless sky without one
+    pub global_radiation: Option<String>,
+    /// Unit of the air temperature
+    pub temperature_unit: TemperatureUnit,
+    /// Unit of the wind speed
+    pub wind_speed_unit: WindSpeedUnit,
+    /// Unit of the relative humidity
+    pub humidity_unit: HumidityUnit,
+    /// Seconds over which the radiation is
+    /// accumulated in J/m2, or none for W/m2
+    pub radiation_period: Option<f64>,
+    /// Cells situated in the city centre
+    pub binnenstad: bool,
+    /// Cells situated in a park
+    pub park: bool,
+    /// Cells situated under the shade of a tree
+    pub shade: bool,
+}
+
+impl Default for GridMapping {
+    fn default() -> GridMapping {
+        GridMapping {
+            time: String::from("time"),
+            latitude: String::from("latitude"),
+            longitude: String::from("longitude"),
+            air_temperature: String::from("air_temperature"),
+            wind_speed: String::from("wind_speed"),
+            relative_humidity: String::from("relative_humidity"),
+            global_radiation: None,
+            temperature_unit: TemperatureUnit::Celsius,
+            wind_speed_unit: WindSpeedUnit::MetresPerSecond,
+            humidity_unit: HumidityUnit::Percent,
+            radiation_period: None,
+            binnenstad: false,
+            park: false,
+            shade: false,
+        }
+    }
+}
+
+impl GridMapping {
+    /// Parses a mapping from TOML, taking the
+    /// defaults for missing keys
+    pub fn from_toml(input: &str) -> Result<GridMapping, Error> {
+        toml::from_str(input)
+            .map_err(|error| Error::NetcdfFile(error.to_string()))
+    }
+}
+
+/// Weather over a grid, with every field
+/// stored in time, y, x order
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Grid {
+    /// Moment of every time step
+    pub time: Vec<DateTime<Utc>>,
+    /// Latitude of every row, or of every cell
+    pub latitude: Vec<f64>,
+    /// Longitude of every column, or of every cell
+    pub longitude: Vec<f64>,
+    /// Number of rows along y
+    pub rows: usize,
+    /// Number of columns along x
+    pub columns: usize,
+    /// Air temperature [C]
+    pub air_temperature: Vec<f32>,
+    /// Wind speed [m/s]
+    pub wind_speed: Vec<f32>,
+    /// Relative humidity [%]
+    pub relative_humidity: Vec<f32>,
+    /// Global horizontal radiation [W/m2],
+    /// empty for a cloudless sky
+    pub global_radiation: Vec<f32>,
+}
+
+impl Grid {
+    /// Number of cells of a time step
+    pub fn cells(&self) -> usize {
+        self.rows * self.columns
+    }
+
+    /// Number of values of a field
+    pub fn len(&self) -> usize {
+        self.time.len() * self.cells()
+    }
+
+    /// Whether the grid holds no values
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Whether the coordinates are given
+    /// per cell rather than per axis
+    pub fn is_curvilinear(&self) -> bool {
+        self.latitude.len() != self.rows || self.longitude.len() != self.columns
+    }
+
+    /// Latitude and longitude of a cell
+    pub fn coordinates(&self, row: usize, column: usize) -> (f64, f64) {
+        if self.is_curvilinear() {
+            let cell = row * self.columns + column;
+            (self.latitude[cell], self.longitude[cell])
+        } else {
+            (self.latitude[row], self.longitude[column])
+        }
+    }
+
+    /// Calculates the UTCI [C] of every cell and
+    /// time step, taking each cell as the given
+    /// kind of location at its coordinates
+    ///
+    /// Values outside of the supported ranges are
+    /// written as NaN and counted in the result.
+    pub fn utci(
+        &self,
+        binnenstad: bool,
+        park: bool,
+        shade: bool,
+    ) -> Result<(Vec<f32>, usize), Error> {
+        self.validate()?;
+
+        let mut locations = Vec::with_capacity(self.cells());
+        for row in 0..self.rows {
+            for column in 0..self.columns {
+                let (latitude, longitude) = self.coordinates(row, column);
+                let site = Site {
+                    latitude,
+                    longitude,
+                    ..Site::groningen(binnenstad, park)
+                };
+                locations.push(Location::new(site, shade)?);
+            }
+        }
+
+        let cells = self.cells();
+        let timestamp: Vec<DateTime<Utc>> = self
+            .time
+            .iter()
+            .flat_map(|moment| std::iter::repeat_n(*moment, cells))
+            .collect();
+        let location: Vec<usize> =
+            (0..self.len()).map(|index| index % cells).collect();
+        let columns = ObservationColumns {
+            air_temperature: &self.air_temperature,
+            wind_speed: &self.wind_speed,
+            relative_humidity: &self.relative_humidity,
+            timestamp: &timestamp,
+            location: &location,
+            global_radiation: &self.global_radiation,
+        };
+
+        let mut utci = vec![0.0; self.len()];
+        let invalid =
+            experienced_heat_stress_batch(&columns, &locations, &mut utci)?;
+        Ok((utci, invalid))
+    }
+
+    // Function to check every field and
+    // coordinate fits the shape of the grid
+    fn validate(&self) -> Result<(), Error> {
+        let len = self.len();
+        let cells = self.cells();
+        let fields = self.air_temperature.len() == len
+            && self.wind_speed.len() == len
+            && self.relative_humidity.len() == len
+            && (self.global_radiation.is_empty()
+                || self.global_radiation.len() == len);
+        let coordinates = (self.latitude.len() == self.rows
+            && self.longitude.len() == self.columns)
+            || (self.latitude.len() == cells && self.longitude.len() == cells);
+        if !fields || !coordinates {
+            return Err(Error::InvalidInput);
+        }
+        Ok(())
+    }
+}
+
+/// Method for reading gridded weather from a
+/// NetCDF file, converted to the units of
+/// the model
+///
+/// Packed variables are unpacked with their
+/// `scale_factor` and `add_offset`, and fill
+/// values are read as NaN. Times are decoded
+/// from their CF `units` in the standard
+/// calendar.
+pub fn read_grid<P: AsRef<Path>>(
+    path: P,
+    mapping: &GridMapping,
+) -> Result<Grid, Error> {
+    let file = netcdf::open(path).map_err(netcdf_error)?;
+    let find = |name: &str| {
+        file.variable(name).ok_or_else(|| {
+            Error::NetcdfFile(format!("variable {} is missing", name))
+        })
+    };
+
+    let temperature = find(&mapping.air_temperature)?;
+    let dimensions = temperature.dimensions();
+    if dimensions.len() != 3 {
+        return Err(Error::NetcdfFile(format!(
+            "{} is not laid out over time, y and x",
+            mapping.air_temperature
+        )));
+    }
+    let (steps, rows, columns) = (
+        dimensions[0].len(),
+        dimensions[1].len(),
+        dimensions[2].len(),
+    );
+    let len = steps * rows * columns;
+
+    let time = read_time(&find(&mapping.time)?)?;
+    if time.len() != steps {
+        return Err(Error::NetcdfFile(format!(
+            "{} does not match the time steps",
+            mapping.time
+        )));
+    }
+
+    let field = |name: &str| -> Result<Vec<f32>, Error> {
+        let values = read_values(&find(name)?)?;
+        if values.len() != len {
+            return Err(Error::NetcdfFile(format!(
+                "{} does not match the shape of {}",
+                name, mapping.air_temperature
+            )));
+        }
+        Ok(values.into_iter().map(|value| value as f32).collect())
+    };
+
+    let global_radiation = match &mapping.global_radiation {
+        Some(name) => {
+            let period = mapping.radiation_period.unwrap_or(1.0) as f32;
+            field(name)?
+                .into_iter()
+                .map(|value| value / period)
+                .collect()
+        }
+        None => Vec::new(),
+    };
+
+    let grid = Grid {
+        time,
+        latitude: read_values(&find(&mapping.latitude)?)?,
+        longitude: read_values(&find(&mapping.longitude)?)?,
+        rows,
+        columns,
+        air_temperature: field(&mapping.air_temperature)?
+            .into_iter()
+            .map(|value| mapping.temperature_unit.to_celsius(value))
+            .collect(),
+        wind_speed: field(&mapping.wind_speed)?
+            .into_iter()
+            .map(|value| mapping.wind_speed_unit.to_metres_per_second(value))
+            .collect(),
+        relative_humidity: field(&mapping.relative_humidity)?
+            .into_iter()
+            .map(|value| mapping.humidity_unit.to_percent(value))
+            .collect(),
+        global_radiation,
+    };
+    grid.validate()?;
+    Ok(grid)
+}
+
+/// Method for writing the UTCI of a grid and its
+/// stress category to a new NetCDF file with
+/// CF metadata
+///
+/// Values outside of the supported ranges, NaN
+/// in `utci`, are written as fill values.
+pub fn write_utci_grid<P: AsRef<Path>>(
+    path: P,
+    grid: &Grid,
+    utci: &[f32],
+) -> Result<(), Error> {
+    grid.validate()?;
+    if utci.len() != grid.len() {
+        return Err(Error::InvalidInput);
+    }
+
+    let mut file = netcdf::create(path).map_err(netcdf_error)?;
+    file.add_attribute("Conventions", "CF-1.8")
+        .map_err(netcdf_error)?;
+    file.add_attribute("title", "Universal Thermal Climate Index")
+        .map_err(netcdf_error)?;
+    file.add_attribute("source", format!("utci {}", env!("CARGO_PKG_VERSION")))
+        .map_err(netcdf_error)?;
+
+    // a regular grid is written along its latitude
+    // and longitude axes, a curvilinear one along
+    // y and x with coordinates for every cell
+    let curvilinear = grid.is_curvilinear();
+    let (y, x) = if curvilinear {
+        ("y", "x")
+    } else {
+        ("latitude", "longitude")
+    };
+    file.add_dimension("time", grid.time.len())
+        .map_err(netcdf_error)?;
+    file.add_dimension(y, grid.rows).map_err(netcdf_error)?;
+    file.add_dimension(x, grid.columns).map_err(netcdf_error)?;
+
+    let seconds: Vec<f64> = grid
+        .time
+        .iter()
+        .map(|moment| moment.timestamp() as f64)
+        .collect();
+    let mut time = file
+        .add_variable::<f64>("time", &["time"])
+        .map_err(netcdf_error)?;
+    put_attributes(
+        &mut time,
+        &[
+            ("standard_name", "time"),
+            ("units", "seconds since 1970-01-01 00:00:00"),
+            ("calendar", "standard"),
+            ("axis", "T"),
+        ],
+    )?;
+    time.put_values(&seconds, ..).map_err(netcdf_error)?;
+
+    let (latitude_dimensions, longitude_dimensions): (&[&str], &[&str]) =
+        if curvilinear {
+            (&[y, x], &[y, x])
+        } else {
+            (&[y], &[x])
+        };
+    let mut latitude = file
+        .add_variable::<f64>("latitude", latitude_dimensions)
+        .map_err(netcdf_error)?;
+    put_attributes(
+        &mut latitude,
+        &[("standard_name", "latitude"), ("units", "degrees_north")],
+    )?;
+    latitude
+        .put_values(&grid.latitude, ..)
+        .map_err(netcdf_error)?;
+    let mut longitude = file
+        .add_variable::<f64>("longitude", longitude_dimensions)
+        .map_err(netcdf_error)?;
+    put_attributes(
+        &mut longitude,
+        &[("standard_name", "longitude"), ("units", "degrees_east")],
+    )?;
+    longitude
+        .put_values(&grid.longitude, ..)
+        .map_err(netcdf_error)?;
+
+    let values: Vec<f32> = utci
+        .iter()
+        .map(|value| if value.is_nan() { FILL_VALUE } else { *value })
+        .collect();
+    let mut variable = file
+        .add_variable::<f32>("utci", &["time", y, x])
+        .map_err(netcdf_error)?;
+    variable.set_fill_value(FILL_VALUE).map_err(netcdf_error)?;
+    put_attributes(
+        &mut variable,
+        &[
+            ("long_name", "Universal Thermal Climate Index"),
+            ("units", "degC"),
+        ],
+    )?;
+    if curvilinear {
+        put_attributes(
+            &mut variable,
+            &[("coordinates", "latitude longitude")],
+        )?;
+    }
+    variable.put_values(&values, ..).map_err(netcdf_error)?;
+
+    let categories: Vec<u8> = utci
+        .iter()
+        .map(|value| {
+            if value.is_nan() {
+                CATEGORY_FILL_VALUE
+            } else {
+                StressCategory::from_utci(*value) as u8
+            }
+        })
+        .collect();
+    let flag_values: Vec<u8> = StressCategory::ALL
+        .iter()
+        .map(|category| *category as u8)
+        .collect();
+    let flag_meanings: Vec<String> = StressCategory::ALL
+        .iter()
+        .map(|category| category.name().replace(' ', "_"))
+        .collect();
+    let flag_meanings = flag_meanings.join(" ");
+    let mut category = file
+        .add_variable::<u8>("utci_category", &["time", y, x])
+        .map_err(netcdf_error)?;
+    category
+        .set_fill_value(CATEGORY_FILL_VALUE)
+        .map_err(netcdf_error)?;
+    put_attributes(
+        &mut category,
+        &[
+            ("long_name", "UTCI assessment scale category"),
+            ("flag_meanings", flag_meanings.as_str()),
+        ],
+    )?;
+    category
+        .put_attribute("flag_values", flag_values)
+        .map_err(netcdf_error)?;
+    if curvilinear {
+        put_attributes(
+            &mut category,
+            &[("coordinates", "latitude longitude")],
+        )?;
+    }
+    category.put_values(&categories, ..).map_err(netcdf_error)?;
+
+    file.close().map_err(netcdf_error)
+}
+
+/// Method for calculating the UTCI of a gridded
+/// NetCDF file and writing it to a new one,
+/// returning the number of values outside of
+/// the supported ranges
+pub fn process_netcdf<P: AsRef<Path>, Q: AsRef<Path>>(
+    input: P,
+    output: Q,
+    mapping: &GridMapping,
+) -> Result<usize, Error> {
+    let grid = read_grid(input, mapping)?;
+    let (utci, invalid) =
+        grid.utci(mapping.binnenstad, mapping.park, mapping.shade)?;
+    write_utci_grid(output, &grid, &utci)?;
+    Ok(invalid)
+}
+
+// Function to read every value of a variable,
+// unpacked and with fill values as NaN
+fn read_values(variable: &Variable) -> Result<Vec<f64>, Error> {
+    let fill_value = number_attribute(variable, "_FillValue")?
+        .or(number_attribute(variable, "missing_value")?);
+    let scale_factor = number_attribute(variable, "scale_factor")?;
+    let add_offset = number_attribute(variable, "add_offset")?;
+
+    let values = variable
+        .get_values::<f64, _>(..)
+        .map_err(netcdf_error)?
+        .into_iter()
+        .map(|value| {
+            if Some(value) == fill_value {
+                return f64::NAN;
+            }
+            value * scale_factor.unwrap_or(1.0) + add_offset.unwrap_or(0.0)
+        })
+        .collect();
+    Ok(values)
+}
+
+// Function to read the moments of a CF
+// time variable
+fn read_time(variable: &Variable) -> Result<Vec<DateTime<Utc>>, Error> {
+    let units = match variable.attribute_value("units") {
+        Some(value) => String::try_from(value.map_err(netcdf_error)?)
+            .map_err(netcdf_error)?,
+        None => return Err(Error::MissingValue(String::from("units"))),
+    };
+    let (step, epoch) = parse_time_units(&units)?;
+
+    read_values(variable)?
+        .into_iter()
+        .map(|value| {
+            if !value.is_finite() {
+                return Err(Error::InvalidTimestamp);
+            }
+            let milliseconds = (value * step * 1000.0).round() as i64;
+            Ok(epoch + Duration::milliseconds(milliseconds))
+        })
+        .collect()
+}
+
+// Function to read CF time units such as
+// `hours since 1900-01-01 00:00:00.0` into the
+// seconds of a step and the epoch
+fn parse_time_units(units: &str) -> Result<(f64, DateTime<Utc>), Error> {
+    let (unit, reference) =
+        units.split_once(" since ").ok_or(Error::InvalidTimestamp)?;
+    let step = match unit.trim() {
+        "seconds" | "second" | "s" => 1.0,
+        "minutes" | "minute" | "min" => 60.0,
+        "hours" | "hour" | "h" => 3600.0,
+        "days" | "day" | "d" => 86400.0,
+        _ => return Err(Error::InvalidTimestamp),
+    };
+
+    let reference = reference
+        .trim()
+        .trim_end_matches("UTC")
+        .trim_end_matches('Z')
+        .trim();
+    let epoch = [
+        "%Y-%m-%d %H:%M:%S%.f",
+        "%Y-%m-%dT%H:%M:%S%.f",
+        "%Y-%m-%d %H:%M",
+    ]
+    .iter()
+    .find_map(|format| NaiveDateTime::parse_from_str(reference, format).ok())
+    .or_else(|| {
+        NaiveDate::parse_from_str(reference, "%Y-%m-%d")
+            .ok()
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+    })
+    .ok_or(Error::InvalidTimestamp)?;
+    Ok((step, Utc.from_utc_datetime(&epoch)))
+}
+
+// Function to read a numeric attribute
+// of a variable
+fn number_attribute(
+    variable: &Variable,
+    name: &str,
+) -> Result<Option<f64>, Error> {
+    match variable.attribute_value(name) {
+        Some(value) => {
+            let value: AttributeValue = value.map_err(netcdf_error)?;
+            f64::try_from(value).map(Some).map_err(netcdf_error)
+        }
+        None => Ok(None),
+    }
+}
+
+// Function to add text attributes to a variable
+fn put_attributes(
+    variable: &mut netcdf::VariableMut,
+    attributes: &[(&str, &str)],
+) -> Result<(), Error> {
+    for (name, value) in attributes {
+        variable.put_attribute(name, *value).map_err(netcdf_error)?;
+    }
+    Ok(())
+}
+
+// Function to describe an error of the
+// NetCDF library
+fn netcdf_error(error: netcdf::Error) -> Error {
+    Error::NetcdfFile(error.to_string())
+}
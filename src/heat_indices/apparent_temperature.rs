@@ -0,0 +1,32 @@
+//! Module containing the Apparent Temperature
+//! of Steadman
+
+use super::utci::{validate_observation, Observation};
+use super::vapour_pressure::{
+    vapour_pressure,
+    SaturationPhase,
+    VapourPressureFormula,
+};
+use crate::error::Error;
+
+/// Method for calculating the Apparent
+/// Temperature [C] of Steadman (1994) in the
+/// shade, from the air temperature, vapour
+/// pressure and wind speed at 10 m
+pub fn apparent_temperature(observation: Observation) -> Result<f32, Error> {
+    validate_observation(
+        observation.air_temperature,
+        observation.wind_speed,
+        observation.relative_humidity,
+    )?;
+    let pressure = vapour_pressure(
+        f64::from(observation.air_temperature),
+        f64::from(observation.relative_humidity),
+        VapourPressureFormula::MagnusTetens,
+        SaturationPhase::Water,
+    );
+    let output = observation.air_temperature + 0.33 * pressure.0 as f32
+        - 0.70 * observation.wind_speed
+        - 4.0;
+    Ok(output)
+}
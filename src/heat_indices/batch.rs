@@ -0,0 +1,331 @@
+//! Module containing the evaluation of
+//! experienced Heat Stress over columns of
+//! observations
+
+use chrono::{DateTime, Utc};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::error::Error;
+use crate::site::Site;
+use crate::solar::SolarPosition;
+
+use crate::heat_indices::polynomial::approximate_chunk;
+use crate::heat_indices::utci::{
+    radiant_temperature_at_site,
+    solar_radiation,
+    validate_observation,
+    Sun,
+    MIN_WIND_SPEED,
+};
+use crate::heat_indices::vapour_pressure::{
+    vapour_pressure,
+    SaturationPhase,
+    VapourPressureFormula,
+};
+
+// Observations handed to a single thread
+#[cfg(feature = "rayon")]
+const CHUNK_SIZE: usize = 1024;
+
+/// Location of a sensor with the terms of the
+/// model that do not change between observations
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Location {
+    site: Site,
+    shade: bool,
+    sky_view_factor: f32,
+}
+
+impl Location {
+    /// Location at the site, under the shade
+    /// of a tree or in the open
+    pub fn new(site: Site, shade: bool) -> Result<Location, Error> {
+        site.validate()?;
+        Ok(Location::precompute(site, shade))
+    }
+
+    /// Location in Groningen for the given
+    /// location flags
+    pub fn groningen(binnenstad: bool, park: bool, shade: bool) -> Location {
+        Location::precompute(Site::groningen(binnenstad, park), shade)
+    }
+
+    // Function to calculate the terms of a
+    // validated site
+    fn precompute(site: Site, shade: bool) -> Location {
+        let sky_view_factor = if shade {
+            site.canyon.sky_view_factor_trees()
+        } else {
+            site.canyon.sky_view_factor()
+        };
+        Location {
+            site,
+            shade,
+            sky_view_factor,
+        }
+    }
+
+    /// Site of the location
+    pub fn site(&self) -> &Site {
+        &self.site
+    }
+
+    /// Whether the location is under the
+    /// shade of a tree
+    pub fn shade(&self) -> bool {
+        self.shade
+    }
+}
+
+/// Observations stored as columns of equal length
+///
+/// Every observation is made at the location with
+/// the index given in `location`, or at the first
+/// location when `location` is empty. Without
+/// `global_radiation` the sky is taken as cloudless.
+#[derive(Debug, Clone, Copy)]
+pub struct ObservationColumns<'a> {
+    /// Air temperature [C]
+    pub air_temperature: &'a [f32],
+    /// Wind speed [m/s]
+    pub wind_speed: &'a [f32],
+    /// Relative humidity [%]
+    pub relative_humidity: &'a [f32],
+    /// Moment of the observation
+    pub timestamp: &'a [DateTime<Utc>],
+    /// Index of the location of the observation
+    pub location: &'a [usize],
+    /// Measured global horizontal radiation [W/m2]
+    pub global_radiation: &'a [f32],
+}
+
+impl<'a> ObservationColumns<'a> {
+    /// Number of observations
+    pub fn len(&self) -> usize {
+        self.air_temperature.len()
+    }
+
+    /// Whether there are no observations
+    pub fn is_empty(&self) -> bool {
+        self.air_temperature.is_empty()
+    }
+
+    // Function to check every column holds
+    // one value per observation
+    fn validate(&self, locations: &[Location]) -> Result<(), Error> {
+        let len = self.len();
+        let equal_columns = self.wind_speed.len() == len
+            && self.relative_humidity.len() == len
+            && self.timestamp.len() == len
+            && (self.location.is_empty() || self.location.len() == len)
+            && (self.global_radiation.is_empty()
+                || self.global_radiation.len() == len);
+        let known_locations = if self.location.is_empty() {
+            len == 0 || !locations.is_empty()
+        } else {
+            self.location.iter().all(|index| *index < locations.len())
+        };
+        if !equal_columns || !known_locations {
+            return Err(Error::InvalidInput);
+        }
+        Ok(())
+    }
+}
+
+/// Method for calculating experienced Heat Stress
+/// for columns of observations, writing the UTCI
+/// [C] of every observation into the output
+///
+/// Observations outside of the supported ranges
+/// are written as NaN and counted in the result.
+/// Columns or an output of unequal length are
+/// reported as an `Error`. With the `rayon`
+/// feature the observations are split over
+/// every core.
+pub fn experienced_heat_stress_batch(
+    columns: &ObservationColumns,
+    locations: &[Location],
+    output: &mut [f32],
+) -> Result<usize, Error> {
+    columns.validate(locations)?;
+    if output.len() != columns.len() {
+        return Err(Error::InvalidInput);
+    }
+
+    #[cfg(feature = "rayon")]
+    let invalid = output
+        .par_chunks_mut(CHUNK_SIZE)
+        .enumerate()
+        .map(|(chunk, output)| {
+            evaluate_chunk(columns, locations, chunk * CHUNK_SIZE, output)
+        })
+        .sum();
+
+    #[cfg(not(feature = "rayon"))]
+    let invalid = evaluate_chunk(columns, locations, 0, output);
+
+    Ok(invalid)
+}
+
+// Inputs of the UTCI polynomial for the
+// observations of a chunk
+struct PolynomialInputs {
+    air_temperature: Vec<f64>,
+    wind_speed: Vec<f64>,
+    temp_diff: Vec<f64>,
+    vapour_pressure: Vec<f64>,
+}
+
+impl PolynomialInputs {
+    // Function to reserve the inputs of
+    // the given number of observations
+    fn with_capacity(len: usize) -> PolynomialInputs {
+        PolynomialInputs {
+            air_temperature: Vec::with_capacity(len),
+            wind_speed: Vec::with_capacity(len),
+            temp_diff: Vec::with_capacity(len),
+            vapour_pressure: Vec::with_capacity(len),
+        }
+    }
+
+    // Function to add the inputs of an
+    // observation, as in the single evaluation
+    fn push(
+        &mut self,
+        air_temperature: f32,
+        wind_speed: f32,
+        relative_humidity: f32,
+        radiant_temperature: f32,
+    ) {
+        let vapour_pressure = vapour_pressure(
+            f64::from(air_temperature),
+            f64::from(relative_humidity),
+            VapourPressureFormula::Hardy,
+            SaturationPhase::Water,
+        )
+        .to_kilopascal();
+        self.air_temperature.push(f64::from(air_temperature));
+        self.wind_speed
+            .push(f64::from(wind_speed.max(MIN_WIND_SPEED)));
+        self.temp_diff
+            .push(f64::from(radiant_temperature - air_temperature));
+        self.vapour_pressure.push(vapour_pressure.0);
+    }
+}
+
+// Function to calculate the UTCI of the
+// observations starting at the offset
+//
+// The radiant temperature is found for every
+// observation first, after which the polynomial
+// is evaluated over the chunk in lanes.
+fn evaluate_chunk(
+    columns: &ObservationColumns,
+    locations: &[Location],
+    offset: usize,
+    output: &mut [f32],
+) -> usize {
+    let mut invalid = 0;
+    let mut inputs = PolynomialInputs::with_capacity(output.len());
+    let mut previous: Option<(DateTime<Utc>, f64, f64, SolarPosition)> = None;
+    for (index, utci) in output.iter_mut().enumerate() {
+        let index = offset + index;
+        let location_index = columns.location.get(index).copied().unwrap_or(0);
+        let site = &locations[location_index].site;
+        let timestamp = columns.timestamp[index];
+
+        // sensors in the same city share the position
+        // of the sun, so it is only recalculated when
+        // the moment or coordinates change
+        let position = match previous {
+            Some((moment, latitude, longitude, position))
+                if moment == timestamp
+                    && latitude == site.latitude
+                    && longitude == site.longitude =>
+            {
+                position
+            }
+            _ => SolarPosition::at(timestamp, site.latitude, site.longitude),
+        };
+        previous = Some((timestamp, site.latitude, site.longitude, position));
+
+        let location = &locations[location_index];
+        let result = sunlight(columns, index, position, site).and_then(|sun| {
+            radiant_temperature(columns, index, location, &sun)
+        });
+        match result {
+            Ok(radiant_temperature) => {
+                inputs.push(
+                    columns.air_temperature[index],
+                    columns.wind_speed[index],
+                    columns.relative_humidity[index],
+                    radiant_temperature,
+                );
+                *utci = 0.0;
+            }
+            Err(_) => {
+                // keeps the inputs aligned with the output
+                inputs.push(0.0, 0.0, 0.0, 0.0);
+                invalid += 1;
+                *utci = f32::NAN;
+            }
+        }
+    }
+
+    let mut approximation = vec![0f64; output.len()];
+    approximate_chunk(
+        &inputs.air_temperature,
+        &inputs.wind_speed,
+        &inputs.temp_diff,
+        &inputs.vapour_pressure,
+        &mut approximation,
+    );
+    for (utci, approximation) in output.iter_mut().zip(approximation) {
+        if !utci.is_nan() {
+            *utci = approximation as f32;
+        }
+    }
+    invalid
+}
+
+// Function to find the sunlight of a row,
+// measured or for a cloudless sky
+fn sunlight(
+    columns: &ObservationColumns,
+    index: usize,
+    position: SolarPosition,
+    site: &Site,
+) -> Result<Sun, Error> {
+    let global_radiation = columns.global_radiation.get(index).copied();
+    let radiation = solar_radiation(&position, global_radiation)?;
+    Ok(Sun::new(
+        radiation,
+        position,
+        site.canyon.is_sunlit(&position),
+    ))
+}
+
+// Function to calculate the mean radiant
+// temperature of a single row of the columns
+fn radiant_temperature(
+    columns: &ObservationColumns,
+    index: usize,
+    location: &Location,
+    sun: &Sun,
+) -> Result<f32, Error> {
+    let air_temperature = columns.air_temperature[index];
+    let wind_speed = columns.wind_speed[index];
+    let relative_humidity = columns.relative_humidity[index];
+    validate_observation(air_temperature, wind_speed, relative_humidity)?;
+
+    radiant_temperature_at_site(
+        air_temperature,
+        wind_speed,
+        relative_humidity,
+        &location.site,
+        location.sky_view_factor,
+        location.shade,
+        sun,
+    )
+}
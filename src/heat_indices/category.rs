@@ -0,0 +1,142 @@
+//! Module containing the UTCI assessment scale
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Categories of thermal stress on the UTCI scale
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum StressCategory {
+    /// UTCI below -40
+    ExtremeColdStress,
+    /// UTCI from -40 to -27
+    VeryStrongColdStress,
+    /// UTCI from -27 to -13
+    StrongColdStress,
+    /// UTCI from -13 to 0
+    ModerateColdStress,
+    /// UTCI from 0 to 9
+    SlightColdStress,
+    /// UTCI from 9 to 26
+    NoThermalStress,
+    /// UTCI from 26 to 32
+    ModerateHeatStress,
+    /// UTCI from 32 to 38
+    StrongHeatStress,
+    /// UTCI from 38 to 46
+    VeryStrongHeatStress,
+    /// UTCI above 46
+    ExtremeHeatStress,
+}
+
+impl StressCategory {
+    /// Every category, from cold to hot
+    pub const ALL: [StressCategory; 10] = [
+        StressCategory::ExtremeColdStress,
+        StressCategory::VeryStrongColdStress,
+        StressCategory::StrongColdStress,
+        StressCategory::ModerateColdStress,
+        StressCategory::SlightColdStress,
+        StressCategory::NoThermalStress,
+        StressCategory::ModerateHeatStress,
+        StressCategory::StrongHeatStress,
+        StressCategory::VeryStrongHeatStress,
+        StressCategory::ExtremeHeatStress,
+    ];
+
+    /// UTCI values [C] separating the categories
+    pub const THRESHOLDS: [f32; 9] =
+        [-40.0, -27.0, -13.0, 0.0, 9.0, 26.0, 32.0, 38.0, 46.0];
+
+    /// Classifies a UTCI equivalent temperature [C]
+    pub fn from_utci(utci: f32) -> StressCategory {
+        if utci > 46.0 {
+            StressCategory::ExtremeHeatStress
+        } else if utci > 38.0 {
+            StressCategory::VeryStrongHeatStress
+        } else if utci > 32.0 {
+            StressCategory::StrongHeatStress
+        } else if utci > 26.0 {
+            StressCategory::ModerateHeatStress
+        } else if utci >= 9.0 {
+            StressCategory::NoThermalStress
+        } else if utci >= 0.0 {
+            StressCategory::SlightColdStress
+        } else if utci >= -13.0 {
+            StressCategory::ModerateColdStress
+        } else if utci >= -27.0 {
+            StressCategory::StrongColdStress
+        } else if utci >= -40.0 {
+            StressCategory::VeryStrongColdStress
+        } else {
+            StressCategory::ExtremeColdStress
+        }
+    }
+
+    /// English name of the category
+    pub fn name(self) -> &'static str {
+        match self {
+            StressCategory::ExtremeColdStress => "extreme cold stress",
+            StressCategory::VeryStrongColdStress => "very strong cold stress",
+            StressCategory::StrongColdStress => "strong cold stress",
+            StressCategory::ModerateColdStress => "moderate cold stress",
+            StressCategory::SlightColdStress => "slight cold stress",
+            StressCategory::NoThermalStress => "no thermal stress",
+            StressCategory::ModerateHeatStress => "moderate heat stress",
+            StressCategory::StrongHeatStress => "strong heat stress",
+            StressCategory::VeryStrongHeatStress => "very strong heat stress",
+            StressCategory::ExtremeHeatStress => "extreme heat stress",
+        }
+    }
+
+    /// Dutch name of the category
+    pub fn dutch_name(self) -> &'static str {
+        match self {
+            StressCategory::ExtremeColdStress => "extreme koudestress",
+            StressCategory::VeryStrongColdStress => "zeer sterke koudestress",
+            StressCategory::StrongColdStress => "sterke koudestress",
+            StressCategory::ModerateColdStress => "matige koudestress",
+            StressCategory::SlightColdStress => "lichte koudestress",
+            StressCategory::NoThermalStress => "geen thermische stress",
+            StressCategory::ModerateHeatStress => "matige hittestress",
+            StressCategory::StrongHeatStress => "sterke hittestress",
+            StressCategory::VeryStrongHeatStress => "zeer sterke hittestress",
+            StressCategory::ExtremeHeatStress => "extreme hittestress",
+        }
+    }
+
+    /// Colour of the category on the UTCI
+    /// scale as a hexadecimal RGB code
+    pub fn colour(self) -> &'static str {
+        match self {
+            StressCategory::ExtremeColdStress => "#000080",
+            StressCategory::VeryStrongColdStress => "#0000C0",
+            StressCategory::StrongColdStress => "#0040FF",
+            StressCategory::ModerateColdStress => "#0080FF",
+            StressCategory::SlightColdStress => "#00C0FF",
+            StressCategory::NoThermalStress => "#00C000",
+            StressCategory::ModerateHeatStress => "#FF8000",
+            StressCategory::StrongHeatStress => "#FF4000",
+            StressCategory::VeryStrongHeatStress => "#C00000",
+            StressCategory::ExtremeHeatStress => "#800000",
+        }
+    }
+}
+
+impl fmt::Display for StressCategory {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
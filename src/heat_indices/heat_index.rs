@@ -0,0 +1,55 @@
+//! Module containing the Heat Index of the
+//! US National Weather Service
+
+use super::utci::{validate_observation, Observation};
+use crate::error::Error;
+
+/// Method for calculating the Heat Index [C]
+/// of the US National Weather Service
+///
+/// The simple formula of Steadman is used below
+/// a Heat Index of 80 F, above it the regression
+/// of Rothfusz (1990) with the adjustments for
+/// dry and humid air.
+pub fn heat_index(observation: Observation) -> Result<f32, Error> {
+    validate_observation(
+        observation.air_temperature,
+        observation.wind_speed,
+        observation.relative_humidity,
+    )?;
+    let temperature = f64::from(observation.air_temperature) * 1.8 + 32.0;
+    let humidity = f64::from(observation.relative_humidity);
+
+    let simple = 0.5
+        * (temperature + 61.0 + (temperature - 68.0) * 1.2 + humidity * 0.094);
+    let fahrenheit = if 0.5 * (simple + temperature) < 80.0 {
+        simple
+    } else {
+        rothfusz(temperature, humidity)
+    };
+    let output = ((fahrenheit - 32.0) / 1.8) as f32;
+    Ok(output)
+}
+
+// Function to calculate the regression of
+// Rothfusz with its adjustments [F]
+fn rothfusz(temperature: f64, humidity: f64) -> f64 {
+    let t = temperature;
+    let rh = humidity;
+    let regression = -42.379 + 2.04901523 * t + 10.14333127 * rh
+        - 0.22475541 * t * rh
+        - 6.83783e-3 * t * t
+        - 5.481717e-2 * rh * rh
+        + 1.22874e-3 * t * t * rh
+        + 8.5282e-4 * t * rh * rh
+        - 1.99e-6 * t * t * rh * rh;
+
+    let adjustment = if rh < 13.0 && (80.0..=112.0).contains(&t) {
+        -(13.0 - rh) / 4.0 * ((17.0 - (t - 95.0).abs()) / 17.0).sqrt()
+    } else if rh > 85.0 && (80.0..=87.0).contains(&t) {
+        (rh - 85.0) / 10.0 * (87.0 - t) / 5.0
+    } else {
+        0.0
+    };
+    regression + adjustment
+}
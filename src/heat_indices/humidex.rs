@@ -0,0 +1,30 @@
+//! Module containing the Humidex of the
+//! Meteorological Service of Canada
+
+use super::utci::{validate_observation, Observation};
+use super::vapour_pressure::{
+    vapour_pressure,
+    SaturationPhase,
+    VapourPressureFormula,
+};
+use crate::error::Error;
+
+/// Method for calculating the Humidex [C]
+/// from the air temperature and the vapour
+/// pressure of the air
+pub fn humidex(observation: Observation) -> Result<f32, Error> {
+    validate_observation(
+        observation.air_temperature,
+        observation.wind_speed,
+        observation.relative_humidity,
+    )?;
+    let pressure = vapour_pressure(
+        f64::from(observation.air_temperature),
+        f64::from(observation.relative_humidity),
+        VapourPressureFormula::MagnusTetens,
+        SaturationPhase::Water,
+    );
+    let output =
+        observation.air_temperature + 0.5555 * (pressure.0 as f32 - 10.0);
+    Ok(output)
+}
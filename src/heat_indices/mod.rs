@@ -1,7 +1,72 @@
+mod apparent_temperature;
+mod batch;
+mod category;
+mod coefficients;
+mod heat_index;
 mod helper;
+mod humidex;
+mod pet;
+mod polynomial;
+mod profile;
 mod utci;
+mod vapour_pressure;
+mod wbgt;
 
-use helper::{
-    calculate_polynomial_regression,
-    calculate_vapour_pressure,
+pub use apparent_temperature::apparent_temperature;
+pub use batch::{
+    experienced_heat_stress_batch,
+    Location,
+    ObservationColumns,
+};
+pub use category::StressCategory;
+pub use coefficients::UTCI_COEFFICIENTS;
+pub use heat_index::heat_index;
+pub use helper::{calculate_polynomial_regression, utci_approx};
+pub use humidex::humidex;
+pub use pet::{
+    experienced_pet,
+    physiological_equivalent_temperature,
+    PetPerson,
+    Sex,
+};
+pub use polynomial::{utci_approx_lanes, utci_approx_slice, UTCI_LANES};
+pub use profile::{
+    experienced_personal_heat_stress,
+    personal_heat_stress,
+    personal_heat_stress_with,
+    AgeGroup,
+    PersonalHeatStress,
+    PersonalProfile,
+    RiskHeuristic,
+    RiskLevel,
+};
+
+pub use vapour_pressure::{
+    saturation_vapour_pressure,
+    vapour_pressure,
+    Hectopascal,
+    Kilopascal,
+    SaturationPhase,
+    VapourPressureFormula,
+};
+pub use wbgt::{
+    wet_bulb_globe_temperature,
+    wet_bulb_globe_temperature_at_site,
+    wet_bulb_globe_temperature_with_radiation,
+};
+
+pub(crate) use utci::{
+    heat_stress_at_site,
+    solar_radiation,
+    utci_at_site,
+    validate_observation,
+    CANOPY_TRANSMISSIVITY,
+    Sun,
+};
+pub use utci::{
+    experienced_heat_stress,
+    experienced_heat_stress_at_site,
+    experienced_heat_stress_in_canyon,
+    HeatStress,
+    Observation,
 };
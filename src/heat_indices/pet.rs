@@ -0,0 +1,557 @@
+//! Module containing the Physiological Equivalent
+//! Temperature of the Munich Energy balance
+//! Model for Individuals (MEMI)
+
+use serde::{Deserialize, Serialize};
+
+use super::utci::{experienced_heat_stress, Observation};
+use super::vapour_pressure::{
+    saturation_vapour_pressure,
+    vapour_pressure,
+    Hectopascal,
+    SaturationPhase,
+    VapourPressureFormula,
+};
+use crate::error::Error;
+
+// Stefan-Boltzmann constant [W/m2/K4]
+const STEFAN_BOLTZMANN: f64 = 5.67e-8;
+
+// Pressure of the air, taken at sea level, and
+// the standard pressure of the convection [hPa]
+const AIR_PRESSURE: f64 = 1013.25;
+const STANDARD_PRESSURE: f64 = 1013.25;
+
+// Saturation formula of MEMI, used for both the
+// air and the skin
+const SATURATION_FORMULA: VapourPressureFormula =
+    VapourPressureFormula::MagnusTetens;
+
+// Specific heat of the air [J/kg/K]
+const SPECIFIC_HEAT_AIR: f64 = 1010.0;
+
+// Latent heat of evaporation of water [J/kg]
+const EVAPORATION_HEAT: f64 = 2.42e6;
+
+// Density [kg/l] and specific heat [J/kg/K]
+// of the blood
+const BLOOD_DENSITY: f64 = 1.06;
+const BLOOD_SPECIFIC_HEAT: f64 = 3640.0;
+
+// Conductance of the tissue between the
+// core and the skin [W/m2/K]
+const TISSUE_CONDUCTANCE: f64 = 5.28;
+
+// Highest flow of blood to the skin [l/m2/h]
+const MAX_BLOOD_FLOW: f64 = 90.0;
+
+// Emissivity of the skin and the clothing
+const SKIN_EMISSIVITY: f64 = 0.99;
+const CLOTHING_EMISSIVITY: f64 = 0.95;
+
+// Fraction of the body surface taking part
+// in the exchange of radiation when standing
+const RADIATING_FRACTION: f64 = 0.725;
+
+// Resistance of the skin to the diffusion
+// of water vapour [s/m]
+const SKIN_DIFFUSION_RESISTANCE: f64 = 0.79e7;
+
+// Set points of the core and skin
+// temperatures [C]
+const CORE_SET_POINT: f64 = 36.6;
+const SKIN_SET_POINT: f64 = 34.0;
+
+// Wind speed and vapour pressure of the
+// reference indoor climate [m/s] [hPa]
+const INDOOR_WIND_SPEED: f64 = 0.1;
+const INDOOR_VAPOUR_PRESSURE: f64 = 12.0;
+
+// Exponent of the power law reducing the wind
+// speed from 10 m to 1.1 m over the city
+const WIND_EXPONENT: f64 = 0.3;
+
+// Width of the interval below which a root
+// has been found [K]
+const TOLERANCE: f64 = 1e-3;
+const MAX_STEPS: usize = 200;
+
+/// Sex of a person, setting the basal
+/// metabolism and sweat rate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Sex {
+    /// Male body
+    Male,
+    /// Female body
+    Female,
+}
+
+/// Body, activity and clothing of the person
+/// for whom PET is calculated
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PetPerson {
+    /// Age [years]
+    pub age: f32,
+    /// Sex of the person
+    pub sex: Sex,
+    /// Body mass [kg]
+    pub weight: f32,
+    /// Body height [m]
+    pub height: f32,
+    /// Metabolism of the activity on top of
+    /// the basal metabolism [W]
+    pub work: f32,
+    /// Clothing insulation [clo]
+    pub clothing: f32,
+}
+
+impl PetPerson {
+    /// Reference person of Höppe (1999), a man
+    /// of 35 years doing light work
+    pub const REFERENCE: PetPerson = PetPerson {
+        age: 35.0,
+        sex: Sex::Male,
+        weight: 75.0,
+        height: 1.75,
+        work: 80.0,
+        clothing: 0.9,
+    };
+
+    /// Checks the body, activity and clothing
+    pub fn validate(&self) -> Result<(), Error> {
+        let valid = (1.0..=120.0).contains(&self.age)
+            && (10.0..=250.0).contains(&self.weight)
+            && (0.5..=2.5).contains(&self.height)
+            && (0.0..=1000.0).contains(&self.work)
+            && (0.1..=2.0).contains(&self.clothing);
+        if !valid {
+            return Err(Error::InvalidProfile);
+        }
+        Ok(())
+    }
+}
+
+impl Default for PetPerson {
+    fn default() -> PetPerson {
+        PetPerson::REFERENCE
+    }
+}
+
+/// Method for calculating the Physiological
+/// Equivalent Temperature [C] in the city of
+/// Groningen
+///
+/// The mean radiant temperature is that of the
+/// UTCI, the vapour pressure follows the
+/// Magnus-Tetens formula of MEMI, and the wind
+/// speed at 10 m is reduced to the height of
+/// the body at 1.1 m.
+pub fn experienced_pet(
+    observation: Observation,
+    person: &PetPerson,
+) -> Result<f32, Error> {
+    let heat_stress = experienced_heat_stress(observation)?;
+    let pressure = vapour_pressure(
+        f64::from(observation.air_temperature),
+        f64::from(observation.relative_humidity),
+        SATURATION_FORMULA,
+        SaturationPhase::Water,
+    );
+    let wind_speed =
+        f64::from(observation.wind_speed) * (1.1f64 / 10.0).powf(WIND_EXPONENT);
+    physiological_equivalent_temperature(
+        observation.air_temperature,
+        heat_stress.radiant_temperature,
+        wind_speed as f32,
+        pressure,
+        person,
+    )
+}
+
+/// Method for calculating the Physiological
+/// Equivalent Temperature [C] from the air
+/// temperature [C], mean radiant temperature
+/// [C], wind speed at 1.1 m [m/s] and vapour
+/// pressure of the air
+///
+/// The core, skin and clothing temperatures
+/// are solved from the two-node energy balance
+/// of MEMI (Höppe, 1999). PET is the air
+/// temperature of a room, with the radiant
+/// temperature equal to it, a wind speed of
+/// 0.1 m/s and a vapour pressure of 12 hPa, in
+/// which the body is in balance with the core,
+/// skin and clothing temperatures and sweating
+/// of outdoors. Inputs out of range, or
+/// a balance which is not found, are reported
+/// as an `Error`.
+pub fn physiological_equivalent_temperature(
+    air_temperature: f32,
+    radiant_temperature: f32,
+    wind_speed: f32,
+    vapour_pressure: Hectopascal,
+    person: &PetPerson,
+) -> Result<f32, Error> {
+    if !(-50.0..=50.0).contains(&air_temperature) {
+        return Err(Error::InvalidAirTemperature);
+    }
+    if !(-30.0..=70.0).contains(&(radiant_temperature - air_temperature)) {
+        return Err(Error::InvalidRadiantTemperature);
+    }
+    if !(0.0..=17.0).contains(&wind_speed) {
+        return Err(Error::InvalidWindSpeed);
+    }
+    if !(0.0..=100.0).contains(&vapour_pressure.0) {
+        return Err(Error::InvalidHumidity);
+    }
+    person.validate()?;
+
+    let body = Body::new(person);
+    let outdoor = Climate {
+        air_temperature: f64::from(air_temperature),
+        radiant_temperature: f64::from(radiant_temperature),
+        wind_speed: f64::from(wind_speed).max(INDOOR_WIND_SPEED),
+        vapour_pressure: vapour_pressure.0,
+    };
+    let state = body.steady_state(&outdoor)?;
+    let sweat = body.sweat(&outdoor, &state);
+
+    // the room in which the body keeps the
+    // temperatures and sweating of outdoors
+    let indoor_balance = |temperature: f64| {
+        let indoor = Climate {
+            air_temperature: temperature,
+            radiant_temperature: temperature,
+            wind_speed: INDOOR_WIND_SPEED,
+            vapour_pressure: INDOOR_VAPOUR_PRESSURE,
+        };
+        Ok(body.heat_balance_with_sweat(&indoor, &state, &sweat))
+    };
+    let output = find_root(indoor_balance, outdoor.air_temperature, 1.0)?;
+    Ok(output as f32)
+}
+
+// Weather around the body
+struct Climate {
+    // air temperature [C]
+    air_temperature: f64,
+    // mean radiant temperature [C]
+    radiant_temperature: f64,
+    // wind speed [m/s]
+    wind_speed: f64,
+    // vapour pressure [hPa]
+    vapour_pressure: f64,
+}
+
+impl Climate {
+    // Function to calculate the convective heat
+    // transfer coefficient of the body [W/m2/K],
+    // corrected for the air pressure
+    fn convection(&self) -> f64 {
+        (2.67 + 6.5 * self.wind_speed.powf(0.67))
+            * (AIR_PRESSURE / STANDARD_PRESSURE).powf(0.55)
+    }
+}
+
+// Temperatures of the nodes of the body [C]
+#[derive(Clone, Copy)]
+struct BodyState {
+    core: f64,
+    skin: f64,
+    clothing: f64,
+}
+
+// Sweat evaporating from the skin
+struct Sweat {
+    // heat lost by evaporation [W]
+    evaporation: f64,
+    // fraction of the skin wetted
+    wetness: f64,
+}
+
+// Properties of the body derived from the
+// person
+struct Body {
+    sex: Sex,
+    // body surface of DuBois [m2]
+    surface: f64,
+    // internal heat production [W]
+    metabolism: f64,
+    // fraction of the surface clothed
+    clothed: f64,
+    // surface of the clothing [m2]
+    clothing_surface: f64,
+    // heat transfer coefficient through
+    // the clothing [W/m2/K]
+    clothing_conductance: f64,
+}
+
+impl Body {
+    // Function to derive the surfaces and
+    // metabolism of the person
+    fn new(person: &PetPerson) -> Body {
+        let weight = f64::from(person.weight);
+        let height = f64::from(person.height);
+        let age = f64::from(person.age);
+        let clothing = f64::from(person.clothing);
+
+        let surface = 0.203 * weight.powf(0.425) * height.powf(0.725);
+        let stature = height * 100.0 / weight.powf(1.0 / 3.0);
+        // basal metabolism of MEMI, metbm for men
+        // and metbf for women
+        let basal = match person.sex {
+            Sex::Male => {
+                3.45 * weight.powf(0.75)
+                    * (1.0 + 0.004 * (30.0 - age) + 0.010 * (stature - 43.4))
+            }
+            Sex::Female => {
+                3.19 * weight.powf(0.75)
+                    * (1.0 + 0.004 * (30.0 - age) + 0.018 * (stature - 42.1))
+            }
+        };
+
+        // the clothing is modelled as a cylinder
+        // around a part of the body
+        let clothed = ((-2.36 + 173.51 * clothing - 100.76 * clothing.powi(2)
+            + 19.28 * clothing.powi(3))
+            / 100.0)
+            .min(1.0);
+        let resistance = clothing / 6.45 / clothed;
+        let covered_height = if clothing <= 0.3 {
+            0.1
+        } else if clothing <= 0.6 {
+            0.5
+        } else if clothing < 2.0 {
+            (height - 0.2) / height
+        } else {
+            1.0
+        };
+        let area_factor = 1.0 + 0.15 * clothing;
+        let circumference =
+            2.0 * std::f64::consts::PI * height * covered_height;
+        let outer = surface * (area_factor - 1.0 + clothed) / circumference;
+        let inner = clothed * surface / circumference;
+        let clothing_surface =
+            surface * clothed + surface * (area_factor - 1.0);
+        let clothing_conductance = circumference * (outer - inner)
+            / (resistance * (outer / inner).ln() * clothing_surface);
+
+        Body {
+            sex: person.sex,
+            surface,
+            metabolism: basal + f64::from(person.work),
+            clothed,
+            clothing_surface,
+            clothing_conductance,
+        }
+    }
+
+    // Function to solve the temperatures of the
+    // body balancing its heat in the climate
+    fn steady_state(&self, climate: &Climate) -> Result<BodyState, Error> {
+        let balance = |clothing: f64| {
+            let skin = self.skin_temperature(climate, clothing);
+            let core = self.core_temperature(climate, skin)?;
+            let state = BodyState {
+                core,
+                skin,
+                clothing,
+            };
+            Ok(-self.heat_balance(climate, &state))
+        };
+        let start = (climate.air_temperature
+            + climate.radiant_temperature
+            + SKIN_SET_POINT)
+            / 3.0;
+        let clothing = find_root(balance, start, 1.0)?;
+        let skin = self.skin_temperature(climate, clothing);
+        let output = BodyState {
+            core: self.core_temperature(climate, skin)?,
+            skin,
+            clothing,
+        };
+        Ok(output)
+    }
+
+    // Function to find the skin temperature
+    // conducting the heat lost by the surface
+    // of the clothing through it [C]
+    fn skin_temperature(&self, climate: &Climate, clothing: f64) -> f64 {
+        let radiation = CLOTHING_EMISSIVITY
+            * STEFAN_BOLTZMANN
+            * ((clothing + 273.2).powi(4)
+                - (climate.radiant_temperature + 273.2).powi(4))
+            * RADIATING_FRACTION;
+        let convection =
+            climate.convection() * (clothing - climate.air_temperature);
+        (convection + radiation) / self.clothing_conductance + clothing
+    }
+
+    // Function to find the core temperature at
+    // which the blood and tissue carry the heat
+    // produced to the skin [C]
+    fn core_temperature(
+        &self,
+        climate: &Climate,
+        skin: f64,
+    ) -> Result<f64, Error> {
+        let produced = self.metabolism + self.respiration(climate);
+        let transported = |core: f64| {
+            let conductance = TISSUE_CONDUCTANCE * self.surface
+                + self.surface
+                    * BLOOD_DENSITY
+                    * BLOOD_SPECIFIC_HEAT
+                    * blood_flow(core, skin)
+                    / 3600.0;
+            Ok(conductance * (core - skin) - produced)
+        };
+        find_root(transported, skin, 1.0)
+    }
+
+    // Function to calculate the sensible and
+    // latent heat exchanged by breathing [W]
+    fn respiration(&self, climate: &Climate) -> f64 {
+        let exhaled = 0.47 * climate.air_temperature + 21.0;
+        let volume = 1.44e-6 * self.metabolism;
+        let sensible =
+            SPECIFIC_HEAT_AIR * (climate.air_temperature - exhaled) * volume;
+        let latent = 0.623 * EVAPORATION_HEAT / AIR_PRESSURE
+            * (climate.vapour_pressure - saturation(exhaled))
+            * volume;
+        sensible + latent
+    }
+
+    // Function to calculate the heat gained by
+    // the body in the climate, zero when in
+    // balance [W]
+    fn heat_balance(&self, climate: &Climate, state: &BodyState) -> f64 {
+        let sweat = self.sweat(climate, state);
+        self.heat_balance_with_sweat(climate, state, &sweat)
+    }
+
+    // Function to calculate the sweat evaporating
+    // up to what the air can take up
+    fn sweat(&self, climate: &Climate, state: &BodyState) -> Sweat {
+        let skin_pressure = saturation(state.skin);
+        let body_temperature = 0.1 * state.skin + 0.9 * state.core;
+        let sweat_rate = match self.sex {
+            Sex::Male => 1.0,
+            Sex::Female => 0.7,
+        } * 304.94
+            * (body_temperature - CORE_SET_POINT).max(0.0)
+            * self.surface
+            / 3.6e6;
+        let sweat = -sweat_rate * EVAPORATION_HEAT;
+        let evaporation_coefficient =
+            0.633 * climate.convection() / (AIR_PRESSURE * SPECIFIC_HEAT_AIR);
+        let potential = evaporation_coefficient
+            * (climate.vapour_pressure - skin_pressure)
+            * self.surface
+            * EVAPORATION_HEAT;
+        let wetness = if potential < 0.0 {
+            (sweat / potential).clamp(0.0, 1.0)
+        } else if sweat_rate > 0.0 {
+            1.0
+        } else {
+            0.0
+        };
+        Sweat {
+            evaporation: sweat.max(potential).min(0.0),
+            wetness,
+        }
+    }
+
+    // Function to calculate the heat gained by
+    // the body with the given sweating [W]
+    fn heat_balance_with_sweat(
+        &self,
+        climate: &Climate,
+        state: &BodyState,
+        sweat: &Sweat,
+    ) -> f64 {
+        let bare = self.surface * (1.0 - self.clothed);
+        let radiant = (climate.radiant_temperature + 273.2).powi(4);
+        let radiation = RADIATING_FRACTION
+            * STEFAN_BOLTZMANN
+            * (bare
+                * SKIN_EMISSIVITY
+                * (radiant - (state.skin + 273.2).powi(4))
+                + self.clothing_surface
+                    * CLOTHING_EMISSIVITY
+                    * (radiant - (state.clothing + 273.2).powi(4)));
+        let convection = climate.convection()
+            * (bare * (climate.air_temperature - state.skin)
+                + self.clothing_surface
+                    * (climate.air_temperature - state.clothing));
+        let diffusion = EVAPORATION_HEAT / SKIN_DIFFUSION_RESISTANCE
+            * self.surface
+            * (1.0 - sweat.wetness)
+            * (climate.vapour_pressure - saturation(state.skin));
+
+        self.metabolism
+            + self.respiration(climate)
+            + radiation
+            + convection
+            + sweat.evaporation
+            + diffusion
+    }
+}
+
+// Function to calculate the flow of blood
+// from the core to the skin [l/m2/h]
+fn blood_flow(core: f64, skin: f64) -> f64 {
+    let dilation = (core - CORE_SET_POINT).max(0.0);
+    let constriction = (SKIN_SET_POINT - skin).max(0.0);
+    ((6.3 + 75.0 * dilation) / (1.0 + 0.5 * constriction)).min(MAX_BLOOD_FLOW)
+}
+
+// Function to calculate the saturation
+// vapour pressure over water [hPa]
+fn saturation(temperature: f64) -> f64 {
+    saturation_vapour_pressure(
+        temperature,
+        SATURATION_FORMULA,
+        SaturationPhase::Water,
+    )
+    .0
+}
+
+// Function to find the root of an increasing
+// function, stepping from the start until its
+// sign changes and halving the interval
+fn find_root<F>(function: F, start: f64, step: f64) -> Result<f64, Error>
+where
+    F: Fn(f64) -> Result<f64, Error>,
+{
+    let value = function(start)?;
+    if value == 0.0 {
+        return Ok(start);
+    }
+    let step = if value > 0.0 { -step } else { step };
+    let mut previous = start;
+    let mut bracket = None;
+    for _ in 0..MAX_STEPS {
+        let next = previous + step;
+        if (function(next)? > 0.0) != (value > 0.0) {
+            bracket = Some((previous, next));
+            break;
+        }
+        previous = next;
+    }
+    let (mut low, mut high) = bracket.ok_or(Error::NoConvergence)?;
+
+    // keep the low end below the root
+    if value > 0.0 {
+        std::mem::swap(&mut low, &mut high);
+    }
+    while (high - low).abs() > TOLERANCE {
+        let middle = 0.5 * (low + high);
+        if function(middle)? > 0.0 {
+            high = middle;
+        } else {
+            low = middle;
+        }
+    }
+    Ok(0.5 * (low + high))
+}
@@ -0,0 +1,186 @@
+//! Module containing the evaluation of the
+//! UTCI_approx polynomial in nested Horner form,
+//! over several observations at once
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::error::Error;
+use crate::heat_indices::coefficients::UTCI_COEFFICIENTS;
+
+/// Number of observations evaluated together
+/// by `utci_approx_slice`, sized for the vector
+/// registers of common processors
+pub const UTCI_LANES: usize = 4;
+
+// Highest power of any input in the polynomial
+const DEGREE: usize = 6;
+
+// Observations handed to a single thread
+#[cfg(feature = "rayon")]
+const CHUNK_SIZE: usize = 4096;
+
+// Coefficients indexed by the powers of vapour
+// pressure, radiant temperature difference, wind
+// speed and air temperature, in the nesting order
+// of the Horner scheme
+type Nested = [[[[f64; DEGREE + 1]; DEGREE + 1]; DEGREE + 1]; DEGREE + 1];
+
+static NESTED_COEFFICIENTS: Nested = nest_coefficients();
+
+// Function to arrange the coefficient table
+// for the Horner scheme at compile time
+const fn nest_coefficients() -> Nested {
+    let mut nested =
+        [[[[0f64; DEGREE + 1]; DEGREE + 1]; DEGREE + 1]; DEGREE + 1];
+    let mut index = 0;
+    while index < UTCI_COEFFICIENTS.len() {
+        let (coefficient, powers) = UTCI_COEFFICIENTS[index];
+        nested[powers[3] as usize][powers[2] as usize][powers[1] as usize]
+            [powers[0] as usize] = coefficient;
+        index += 1;
+    }
+    nested
+}
+
+/// Evaluates the UTCI_approx polynomial for
+/// `N` observations at once, each input given
+/// as in `utci_approx`
+///
+/// Every lane follows the same sequence of
+/// operations, which allows the compiler to
+/// use vector instructions.
+pub fn utci_approx_lanes<const N: usize>(
+    air_temperature: &[f64; N],
+    wind_speed: &[f64; N],
+    temp_diff: &[f64; N],
+    vapour_pressure: &[f64; N],
+) -> [f64; N] {
+    let mut sum = [0f64; N];
+    for pa in (0..=DEGREE).rev() {
+        let mut sum_pa = [0f64; N];
+        for d in (0..=DEGREE - pa).rev() {
+            let mut sum_d = [0f64; N];
+            for va in (0..=DEGREE - pa - d).rev() {
+                let row = &NESTED_COEFFICIENTS[pa][d][va];
+                let mut sum_va = [0f64; N];
+                for coefficient in row[..=DEGREE - pa - d - va].iter().rev() {
+                    sum_va = multiply_add(&sum_va, air_temperature, |_| {
+                        *coefficient
+                    });
+                }
+                sum_d = multiply_add(&sum_d, wind_speed, |n| sum_va[n]);
+            }
+            sum_pa = multiply_add(&sum_pa, temp_diff, |n| sum_d[n]);
+        }
+        sum = multiply_add(&sum, vapour_pressure, |n| sum_pa[n]);
+    }
+
+    // the polynomial gives the offset from air temperature
+    std::array::from_fn(|n| air_temperature[n] + sum[n])
+}
+
+/// Evaluates the UTCI_approx polynomial for
+/// every element of the slices, writing the
+/// result into the output
+///
+/// Slices of unequal length are reported
+/// as an `Error`. With the `rayon` feature
+/// the slices are split over every core.
+pub fn utci_approx_slice(
+    air_temperature: &[f64],
+    wind_speed: &[f64],
+    temp_diff: &[f64],
+    vapour_pressure: &[f64],
+    output: &mut [f64],
+) -> Result<(), Error> {
+    let len = output.len();
+    if air_temperature.len() != len
+        || wind_speed.len() != len
+        || temp_diff.len() != len
+        || vapour_pressure.len() != len
+    {
+        return Err(Error::InvalidInput);
+    }
+
+    #[cfg(feature = "rayon")]
+    output.par_chunks_mut(CHUNK_SIZE).enumerate().for_each(
+        |(chunk, output)| {
+            let range = chunk * CHUNK_SIZE..chunk * CHUNK_SIZE + output.len();
+            approximate_chunk(
+                &air_temperature[range.clone()],
+                &wind_speed[range.clone()],
+                &temp_diff[range.clone()],
+                &vapour_pressure[range],
+                output,
+            )
+        },
+    );
+
+    #[cfg(not(feature = "rayon"))]
+    approximate_chunk(
+        air_temperature,
+        wind_speed,
+        temp_diff,
+        vapour_pressure,
+        output,
+    );
+
+    Ok(())
+}
+
+// Function to evaluate slices of equal length
+// in groups of `UTCI_LANES`
+pub(super) fn approximate_chunk(
+    air_temperature: &[f64],
+    wind_speed: &[f64],
+    temp_diff: &[f64],
+    vapour_pressure: &[f64],
+    output: &mut [f64],
+) {
+    let mut start = 0;
+    for lanes in output.chunks_mut(UTCI_LANES) {
+        let end = start + lanes.len();
+        if lanes.len() == UTCI_LANES {
+            let result = utci_approx_lanes::<UTCI_LANES>(
+                &lane(&air_temperature[start..end]),
+                &lane(&wind_speed[start..end]),
+                &lane(&temp_diff[start..end]),
+                &lane(&vapour_pressure[start..end]),
+            );
+            lanes.copy_from_slice(&result);
+        } else {
+            for (index, value) in lanes.iter_mut().enumerate() {
+                let [result] = utci_approx_lanes(
+                    &[air_temperature[start + index]],
+                    &[wind_speed[start + index]],
+                    &[temp_diff[start + index]],
+                    &[vapour_pressure[start + index]],
+                );
+                *value = result;
+            }
+        }
+        start = end;
+    }
+}
+
+// Function to copy a full group of
+// observations into a lane array
+fn lane(values: &[f64]) -> [f64; UTCI_LANES] {
+    let mut lane = [0f64; UTCI_LANES];
+    lane.copy_from_slice(values);
+    lane
+}
+
+// Function to calculate sum * x + addend(n)
+// for every lane
+fn multiply_add<const N: usize, F>(
+    sum: &[f64; N],
+    x: &[f64; N],
+    addend: F,
+) -> [f64; N]
+where
+    F: Fn(usize) -> f64,
+{
+    std::array::from_fn(|n| sum[n] * x[n] + addend(n))
+}
@@ -0,0 +1,331 @@
+//! Module containing the personal thermal
+//! profile and the heat risk it leads to
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use super::utci::{experienced_heat_stress, HeatStress, Observation};
+use crate::error::Error;
+
+// Metabolic rate of the UTCI reference person,
+// walking at 4 km/h [W/m2], Bröde et al. (2012),
+// Int J Biometeorol 56, 481-494
+const REFERENCE_METABOLIC_RATE: f32 = 135.0;
+
+// Clothing insulation of typical summer
+// clothing [clo], ASHRAE Standard 55
+const REFERENCE_CLOTHING: f32 = 0.5;
+
+/// Sensitivities and margins turning a profile
+/// into a personal temperature and risk
+///
+/// These are a heuristic for ranking risk, not
+/// fitted to a thermo-physiological model or
+/// taken from a standard. `RiskHeuristic::DEFAULT`
+/// can be overridden field by field.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RiskHeuristic {
+    /// Rise of the personal temperature for every
+    /// W/m2 of metabolic heat above the reference
+    /// person [K m2/W]
+    pub metabolic_sensitivity: f32,
+    /// Rise of the personal temperature for every
+    /// clo of clothing above the reference person [K]
+    pub clothing_sensitivity: f32,
+    /// Lowering of the risk thresholds for people
+    /// not used to hot weather [K]
+    pub acclimatisation_margin: f32,
+    /// Lowering of the risk thresholds for
+    /// children [K]
+    pub child_margin: f32,
+    /// Lowering of the risk thresholds for
+    /// the elderly [K]
+    pub elderly_margin: f32,
+}
+
+impl RiskHeuristic {
+    /// Values used when none are given
+    pub const DEFAULT: RiskHeuristic = RiskHeuristic {
+        metabolic_sensitivity: 0.05,
+        clothing_sensitivity: 4.0,
+        acclimatisation_margin: 2.0,
+        child_margin: 2.0,
+        elderly_margin: 4.0,
+    };
+
+    /// Lowering of the risk thresholds for the
+    /// thermoregulation of an age group [K]
+    pub fn age_margin(&self, age_group: AgeGroup) -> f32 {
+        match age_group {
+            AgeGroup::Child => self.child_margin,
+            AgeGroup::Adult => 0.0,
+            AgeGroup::Elderly => self.elderly_margin,
+        }
+    }
+
+    /// Shift of the personal temperature from
+    /// the UTCI for the clothing and activity [K]
+    pub fn temperature_offset(&self, profile: &PersonalProfile) -> f32 {
+        let metabolic = self.metabolic_sensitivity
+            * (profile.metabolic_rate - REFERENCE_METABOLIC_RATE);
+        let clothing =
+            self.clothing_sensitivity * (profile.clothing - REFERENCE_CLOTHING);
+        metabolic + clothing
+    }
+
+    /// Lowering of the risk thresholds for the
+    /// age and acclimatisation [K]
+    pub fn vulnerability_margin(&self, profile: &PersonalProfile) -> f32 {
+        let acclimatisation = if profile.acclimatised {
+            0.0
+        } else {
+            self.acclimatisation_margin
+        };
+        self.age_margin(profile.age_group) + acclimatisation
+    }
+}
+
+impl Default for RiskHeuristic {
+    fn default() -> RiskHeuristic {
+        RiskHeuristic::DEFAULT
+    }
+}
+
+/// Age group of a person
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AgeGroup {
+    /// Children under 12 years
+    Child,
+    /// Adults from 12 to 65 years
+    Adult,
+    /// Elderly from 65 years
+    Elderly,
+}
+
+impl AgeGroup {
+    /// Lowering of the risk thresholds for the
+    /// weaker thermoregulation of the group with
+    /// the default heuristic [K]
+    pub fn margin(self) -> f32 {
+        RiskHeuristic::DEFAULT.age_margin(self)
+    }
+}
+
+/// Clothing, activity and vulnerability of a
+/// person, next to the reference person of
+/// the UTCI
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PersonalProfile {
+    /// Clothing insulation [clo]
+    pub clothing: f32,
+    /// Metabolic rate of the activity [W/m2]
+    pub metabolic_rate: f32,
+    /// Age group of the person
+    pub age_group: AgeGroup,
+    /// Used to hot weather
+    pub acclimatised: bool,
+}
+
+impl PersonalProfile {
+    /// Reference person of the UTCI, an adult
+    /// walking at 4 km/h
+    pub const REFERENCE: PersonalProfile = PersonalProfile {
+        clothing: REFERENCE_CLOTHING,
+        metabolic_rate: REFERENCE_METABOLIC_RATE,
+        age_group: AgeGroup::Adult,
+        acclimatised: true,
+    };
+
+    /// Example of an elderly resident walking
+    /// slowly in long sleeves
+    pub const ELDERLY: PersonalProfile = PersonalProfile {
+        clothing: 0.7,
+        metabolic_rate: 100.0,
+        age_group: AgeGroup::Elderly,
+        acclimatised: false,
+    };
+
+    /// Checks the clothing and metabolic rate,
+    /// from resting at 1 met (58 W/m2, ISO 8996)
+    pub fn validate(&self) -> Result<(), Error> {
+        if !(0.0..=2.0).contains(&self.clothing)
+            || !(58.0..=400.0).contains(&self.metabolic_rate)
+        {
+            return Err(Error::InvalidProfile);
+        }
+        Ok(())
+    }
+
+    /// Shift of the personal temperature from the
+    /// UTCI with the default heuristic [K]
+    pub fn temperature_offset(&self) -> f32 {
+        RiskHeuristic::DEFAULT.temperature_offset(self)
+    }
+
+    /// Lowering of the risk thresholds with the
+    /// default heuristic [K]
+    pub fn vulnerability_margin(&self) -> f32 {
+        RiskHeuristic::DEFAULT.vulnerability_margin(self)
+    }
+}
+
+impl Default for PersonalProfile {
+    fn default() -> PersonalProfile {
+        PersonalProfile::REFERENCE
+    }
+}
+
+/// Levels of personal heat risk
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum RiskLevel {
+    /// No heat stress expected
+    Low,
+    /// Moderate heat stress
+    Moderate,
+    /// Strong heat stress
+    High,
+    /// Very strong heat stress
+    VeryHigh,
+    /// Extreme heat stress
+    Extreme,
+}
+
+impl RiskLevel {
+    /// Every level, from low to extreme
+    pub const ALL: [RiskLevel; 5] = [
+        RiskLevel::Low,
+        RiskLevel::Moderate,
+        RiskLevel::High,
+        RiskLevel::VeryHigh,
+        RiskLevel::Extreme,
+    ];
+
+    /// Classifies a personal temperature [C] on
+    /// the heat side of the UTCI assessment scale
+    /// of Bröde et al. (2012), with the thresholds
+    /// lowered by the margin [K]
+    pub fn from_temperature(temperature: f32, margin: f32) -> RiskLevel {
+        let temperature = temperature + margin;
+        if temperature > 46.0 {
+            RiskLevel::Extreme
+        } else if temperature > 38.0 {
+            RiskLevel::VeryHigh
+        } else if temperature > 32.0 {
+            RiskLevel::High
+        } else if temperature > 26.0 {
+            RiskLevel::Moderate
+        } else {
+            RiskLevel::Low
+        }
+    }
+
+    /// English name of the level
+    pub fn name(self) -> &'static str {
+        match self {
+            RiskLevel::Low => "low",
+            RiskLevel::Moderate => "moderate",
+            RiskLevel::High => "high",
+            RiskLevel::VeryHigh => "very high",
+            RiskLevel::Extreme => "extreme",
+        }
+    }
+
+    /// Dutch name of the level
+    pub fn dutch_name(self) -> &'static str {
+        match self {
+            RiskLevel::Low => "laag",
+            RiskLevel::Moderate => "matig",
+            RiskLevel::High => "hoog",
+            RiskLevel::VeryHigh => "zeer hoog",
+            RiskLevel::Extreme => "extreem",
+        }
+    }
+}
+
+impl fmt::Display for RiskLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Experienced Heat Stress of an observation
+/// with the risk for a personal profile
+///
+/// The standard UTCI is kept unchanged, the
+/// personal temperature is an estimate for
+/// ranking the risk and not a UTCI.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PersonalHeatStress {
+    /// Standard experienced Heat Stress
+    pub standard: HeatStress,
+    /// UTCI shifted for the clothing and
+    /// activity of the person [C]
+    pub personal_temperature: f32,
+    /// Personal heat risk
+    pub risk: RiskLevel,
+}
+
+/// Method for assessing the heat risk of a
+/// person from the standard Heat Stress
+///
+/// The UTCI is shifted linearly for the
+/// clothing and metabolic rate away from the
+/// reference person, and classified with the
+/// thresholds lowered for children, the
+/// elderly and people not acclimatised, all
+/// with `RiskHeuristic::DEFAULT`.
+pub fn personal_heat_stress(
+    heat_stress: HeatStress,
+    profile: &PersonalProfile,
+) -> Result<PersonalHeatStress, Error> {
+    personal_heat_stress_with(heat_stress, profile, &RiskHeuristic::DEFAULT)
+}
+
+/// Method for assessing the heat risk of a
+/// person with a chosen heuristic
+pub fn personal_heat_stress_with(
+    heat_stress: HeatStress,
+    profile: &PersonalProfile,
+    heuristic: &RiskHeuristic,
+) -> Result<PersonalHeatStress, Error> {
+    profile.validate()?;
+    let personal_temperature =
+        heat_stress.utci + heuristic.temperature_offset(profile);
+    let risk = RiskLevel::from_temperature(
+        personal_temperature,
+        heuristic.vulnerability_margin(profile),
+    );
+    let output = PersonalHeatStress {
+        standard: heat_stress,
+        personal_temperature,
+        risk,
+    };
+    Ok(output)
+}
+
+/// Method for calculating experienced
+/// Heat Stress in the city of Groningen
+/// with the risk for a personal profile
+pub fn experienced_personal_heat_stress(
+    observation: Observation,
+    profile: &PersonalProfile,
+) -> Result<PersonalHeatStress, Error> {
+    profile.validate()?;
+    let heat_stress = experienced_heat_stress(observation)?;
+    personal_heat_stress(heat_stress, profile)
+}
@@ -0,0 +1,436 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::canyon::CanyonGeometry;
+use crate::error::Error;
+use crate::materials::Surfaces;
+use crate::radiation::{
+    mean_radiant_temperature,
+    Direction,
+    DirectionalFluxes,
+    STEFAN_BOLTZMANN,
+};
+use crate::site::Site;
+use crate::solar::{SolarPosition, SolarRadiation};
+
+use crate::heat_indices::{
+    calculate_polynomial_regression,
+    StressCategory,
+    saturation_vapour_pressure,
+    vapour_pressure,
+    SaturationPhase,
+    VapourPressureFormula,
+};
+
+// Heat transfer coefficient between the ground
+// and the air [W/m2/K]
+const SURFACE_HEAT_TRANSFER: f32 = 40.0;
+
+// Fraction of direct sunlight passing through
+// the canopy of a tree
+pub(crate) const CANOPY_TRANSMISSIVITY: f32 = 0.1;
+
+// Lowest wind speed for which the polynomial
+// is fitted [m/s]
+pub(super) const MIN_WIND_SPEED: f32 = 0.5;
+
+/// Observation of the weather and surroundings
+/// from which experienced Heat Stress is calculated
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Observation {
+    /// Air temperature [C]
+    pub air_temperature: f32,
+    /// Wind speed [m/s]
+    pub wind_speed: f32,
+    /// Relative humidity [%]
+    pub relative_humidity: f32,
+    /// Situated in the city centre
+    pub binnenstad: bool,
+    /// Situated in a park
+    pub park: bool,
+    /// Situated under the shade of a tree
+    pub shade: bool,
+    /// Moment of the observation
+    pub timestamp: DateTime<Utc>,
+}
+
+/// Experienced Heat Stress of an observation
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct HeatStress {
+    /// UTCI equivalent temperature [C]
+    pub utci: f32,
+    /// Mean radiant temperature [C]
+    pub radiant_temperature: f32,
+}
+
+impl HeatStress {
+    /// Category of the UTCI assessment scale
+    pub fn category(&self) -> StressCategory {
+        StressCategory::from_utci(self.utci)
+    }
+}
+
+/// Method for calculating experienced
+/// Heat Stress in the city of Groningen
+///
+/// A park within the city centre is
+/// modelled as a park.
+pub fn experienced_heat_stress(
+    observation: Observation,
+) -> Result<HeatStress, Error> {
+    let site = Site::groningen(observation.binnenstad, observation.park);
+    experienced_heat_stress_at_site(observation, &site)
+}
+
+/// Method for calculating experienced
+/// Heat Stress within the given street canyon
+pub fn experienced_heat_stress_in_canyon(
+    observation: Observation,
+    canyon: &CanyonGeometry,
+) -> Result<HeatStress, Error> {
+    let site = Site {
+        canyon: *canyon,
+        ..Site::groningen(observation.binnenstad, observation.park)
+    };
+    experienced_heat_stress_at_site(observation, &site)
+}
+
+/// Method for calculating experienced
+/// Heat Stress at the given site
+pub fn experienced_heat_stress_at_site(
+    observation: Observation,
+    site: &Site,
+) -> Result<HeatStress, Error> {
+    heat_stress_at_site(observation, site, None)
+}
+
+// Function to calculate experienced Heat Stress at
+// a site under a measured global radiation [W/m2],
+// or under a cloudless sky without a measurement
+pub(crate) fn heat_stress_at_site(
+    observation: Observation,
+    site: &Site,
+    global_radiation: Option<f32>,
+) -> Result<HeatStress, Error> {
+    let Observation {
+        air_temperature,
+        wind_speed,
+        relative_humidity,
+        shade,
+        timestamp,
+        ..
+    } = observation;
+
+    validate_observation(
+        air_temperature,
+        wind_speed,
+        relative_humidity,
+    )?;
+    site.validate()?;
+
+    let position =
+        SolarPosition::at(timestamp, site.latitude, site.longitude);
+    let sun = Sun::new(
+        solar_radiation(&position, global_radiation)?,
+        position,
+        site.canyon.is_sunlit(&position),
+    );
+    let svf = if shade {
+        site.canyon.sky_view_factor_trees()
+    } else {
+        site.canyon.sky_view_factor()
+    };
+
+    utci_at_site(
+        air_temperature,
+        wind_speed,
+        relative_humidity,
+        site,
+        svf,
+        shade,
+        &sun,
+    )
+}
+
+// Function to calculate the model for a validated
+// observation with the sky view factor and sunlight
+// of its site
+pub(crate) fn utci_at_site(
+    air_temperature: f32,
+    wind_speed: f32,
+    relative_humidity: f32,
+    site: &Site,
+    svf: f32,
+    shade: bool,
+    sun: &Sun,
+) -> Result<HeatStress, Error> {
+    let radiant_temperature = radiant_temperature_at_site(
+        air_temperature,
+        wind_speed,
+        relative_humidity,
+        site,
+        svf,
+        shade,
+        sun,
+    )?;
+
+    let utci = calculate_polynomial_regression(
+        air_temperature,
+        radiant_temperature,
+        wind_speed.max(MIN_WIND_SPEED),
+        relative_humidity,
+    );
+    Ok(HeatStress {
+        utci,
+        radiant_temperature,
+    })
+}
+
+// Function to calculate the mean radiant
+// temperature of a validated observation
+// within the range of the UTCI polynomial [C]
+pub(crate) fn radiant_temperature_at_site(
+    air_temperature: f32,
+    wind_speed: f32,
+    relative_humidity: f32,
+    site: &Site,
+    svf: f32,
+    shade: bool,
+    sun: &Sun,
+) -> Result<f32, Error> {
+    // the canopy overhead cools by transpiration
+    let canopy_cooling = if shade {
+        Some(compute_cooling_energy(
+            air_temperature,
+            relative_humidity,
+            wind_speed.max(MIN_WIND_SPEED),
+        ))
+    } else {
+        None
+    };
+
+    let fluxes = directional_fluxes(
+        svf,
+        air_temperature,
+        relative_humidity,
+        sun,
+        &site.surfaces,
+        canopy_cooling,
+    );
+    let radiant_temperature = mean_radiant_temperature(&fluxes)?;
+
+    // range of the UTCI polynomial
+    let temp_diff = radiant_temperature - air_temperature;
+    if !(-30.0..=70.0).contains(&temp_diff) {
+        return Err(Error::InvalidRadiantTemperature);
+    }
+    Ok(radiant_temperature)
+}
+
+// Function to check the observation is within
+// the range supported by the models
+pub(crate) fn validate_observation(
+    air_temperature: f32,
+    wind_speed: f32,
+    relative_humidity: f32,
+) -> Result<(), Error> {
+    if !(-50.0..=50.0).contains(&air_temperature) {
+        return Err(Error::InvalidAirTemperature);
+    }
+    if !(0.0..=100.0).contains(&relative_humidity) {
+        return Err(Error::InvalidHumidity);
+    }
+    if !(0.0..=17.0).contains(&wind_speed) {
+        return Err(Error::InvalidWindSpeed);
+    }
+    Ok(())
+}
+
+// Highest global radiation accepted from
+// a measurement [W/m2]
+const MAX_GLOBAL_RADIATION: f32 = 1400.0;
+
+// Sunlight reaching the street at the
+// moment of the observation
+pub(crate) struct Sun {
+    radiation: SolarRadiation,
+    position: SolarPosition,
+    sunlit: bool,
+}
+
+impl Sun {
+    // Function to combine the radiation with
+    // whether it reaches the observation
+    pub(crate) fn new(
+        radiation: SolarRadiation,
+        position: SolarPosition,
+        sunlit: bool,
+    ) -> Sun {
+        Sun {
+            radiation,
+            position,
+            sunlit,
+        }
+    }
+}
+
+// Function to find the radiation of a measured
+// global radiation [W/m2], or of a cloudless
+// sky without a measurement
+pub(crate) fn solar_radiation(
+    position: &SolarPosition,
+    global_radiation: Option<f32>,
+) -> Result<SolarRadiation, Error> {
+    match global_radiation {
+        Some(global) if (0.0..=MAX_GLOBAL_RADIATION).contains(&global) => {
+            Ok(SolarRadiation::from_global_horizontal(
+                f64::from(global),
+                position,
+            ))
+        }
+        Some(_) => Err(Error::InvalidRadiantFlux),
+        None => Ok(SolarRadiation::clear_sky(position)),
+    }
+}
+
+// Function to calculate the short and long wave
+// radiation reaching a person from six directions,
+// with the cooling energy of the canopy overhead
+// when shaded by a tree
+fn directional_fluxes(
+    sky_view_factor: f32,
+    air_temperature: f32,
+    relative_humidity: f32,
+    sun: &Sun,
+    surfaces: &Surfaces,
+    canopy_cooling: Option<f32>,
+) -> DirectionalFluxes {
+    let transmissivity = match canopy_cooling {
+        Some(_) => CANOPY_TRANSMISSIVITY,
+        None => 1f32,
+    };
+    let direct_normal = if sun.sunlit {
+        sun.radiation.direct_normal as f32 * transmissivity
+    } else {
+        0f32
+    };
+    let cos_zenith = sun.radiation.cos_zenith as f32;
+    let elevation = sun.position.elevation().to_radians() as f32;
+    let diffuse = sun.radiation.diffuse_horizontal as f32 * sky_view_factor;
+
+    // short wave from the sky, the ground and the walls
+    let k_sky = direct_normal * cos_zenith + diffuse;
+    let k_ground = surfaces.ground.albedo * k_sky;
+    let k_walls = surfaces.walls.albedo
+        * sun.radiation.global_horizontal() as f32
+        * (1f32 - sky_view_factor);
+
+    // long wave from the sky, the ground and the walls
+    let kelvin_temperature = air_temperature + 273.15;
+    let emission = STEFAN_BOLTZMANN * f32::powi(kelvin_temperature, 4i32);
+    let l_sky = sky_emissivity(air_temperature, relative_humidity) * emission;
+    let l_walls = surfaces.walls.emissivity * emission;
+    let ground_temperature = kelvin_temperature
+        + (1f32 - surfaces.ground.albedo) * k_sky / SURFACE_HEAT_TRANSFER;
+    let l_ground = surfaces.ground.emissivity
+        * STEFAN_BOLTZMANN
+        * f32::powi(ground_temperature, 4i32);
+    let l_open =
+        sky_view_factor * l_sky + (1f32 - sky_view_factor) * l_walls;
+    let l_above = match canopy_cooling {
+        Some(q_cooling) => {
+            let l_canopy = surfaces.canopy.emissivity * emission - q_cooling;
+            (1f32 - transmissivity) * l_canopy + transmissivity * l_open
+        }
+        None => l_open,
+    };
+
+    let mut fluxes = DirectionalFluxes::default();
+    fluxes.set(Direction::Up, k_sky, l_above);
+    fluxes.set(Direction::Down, k_ground, l_ground);
+    for direction in Direction::ALL.iter().skip(2) {
+        let azimuth = direction.azimuth().unwrap_or(0.0);
+        let incidence = (sun.position.azimuth as f32 - azimuth)
+            .to_radians()
+            .cos()
+            .max(0f32);
+        let k_side = direct_normal * elevation.cos() * incidence
+            + 0.5 * (diffuse + k_ground + k_walls);
+        let l_side = 0.5 * (l_above + l_ground);
+        fluxes.set(*direction, k_side, l_side);
+    }
+    fluxes
+}
+
+// Function to calculate the emissivity of the
+// clear sky following Brutsaert (1975)
+fn sky_emissivity(air_temperature: f32, relative_humidity: f32) -> f32 {
+    let pressure = vapour_pressure(
+        f64::from(air_temperature),
+        f64::from(relative_humidity),
+        VapourPressureFormula::Hardy,
+        SaturationPhase::Water,
+    )
+    .0 as f32;
+    let kelvin_temperature = air_temperature + 273.15;
+    1.24 * f32::powf(pressure / kelvin_temperature, 1f32 / 7f32)
+}
+
+// Function to calculate cooling energy from
+// trees
+fn compute_cooling_energy(
+    air_temperature: f32,
+    relative_humidity: f32,
+    wind_speed: f32,
+) -> f32 {
+    let c_leaf = calculate_c_leaf(air_temperature);
+    let c_air =
+        calculate_c_air(air_temperature, relative_humidity);
+    let numerator_1 = c_leaf - c_air;
+    let denominator_1 = 0.125
+        + calculate_aerodynamic_resistance(wind_speed);
+    let sum_1 = numerator_1 / denominator_1;
+    let sum_2 = 3600f32 / 3.73f32;
+    let output = sum_1 * sum_2;
+    (output * 2450f32) / 3600f32
+}
+
+// Function to calculate transpiration of leaves
+fn calculate_c_leaf(air_temperature: f32) -> f32 {
+    let kelvin_temperature = air_temperature + 273.15;
+    let saturation = saturation_vapour_pressure(
+        f64::from(air_temperature),
+        VapourPressureFormula::Hardy,
+        SaturationPhase::Water,
+    )
+    .0 as f32;
+    let numerator = 18f32 * saturation;
+    let denominator = 8.314 * kelvin_temperature;
+    numerator / denominator
+}
+
+// Function to calculate transpiration of air
+fn calculate_c_air(
+    air_temperature: f32,
+    relative_humidity: f32,
+) -> f32 {
+    let kelvin_temperature = air_temperature + 273.15;
+    let pressure = vapour_pressure(
+        f64::from(air_temperature),
+        f64::from(relative_humidity),
+        VapourPressureFormula::Hardy,
+        SaturationPhase::Water,
+    )
+    .0 as f32;
+    let numerator = 18f32 * pressure;
+    let denominator = 8.314 * kelvin_temperature;
+    numerator / denominator
+}
+
+// Function to calculate aerodynamic resistance 
+// of trees
+fn calculate_aerodynamic_resistance(
+    wind_speed: f32,
+) -> f32 {
+    wind_speed / (0.7921)
+}
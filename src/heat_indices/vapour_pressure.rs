@@ -0,0 +1,197 @@
+//! Module containing the saturation vapour
+//! pressure formulations
+
+/// Pressure in hectopascal [hPa]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Hectopascal(pub f64);
+
+/// Pressure in kilopascal [kPa]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Kilopascal(pub f64);
+
+impl Hectopascal {
+    /// Converts the pressure to kilopascal
+    pub fn to_kilopascal(self) -> Kilopascal {
+        Kilopascal(self.0 / 10.0)
+    }
+}
+
+impl Kilopascal {
+    /// Converts the pressure to hectopascal
+    pub fn to_hectopascal(self) -> Hectopascal {
+        Hectopascal(self.0 * 10.0)
+    }
+}
+
+impl From<Hectopascal> for Kilopascal {
+    fn from(pressure: Hectopascal) -> Kilopascal {
+        pressure.to_kilopascal()
+    }
+}
+
+impl From<Kilopascal> for Hectopascal {
+    fn from(pressure: Kilopascal) -> Hectopascal {
+        pressure.to_hectopascal()
+    }
+}
+
+/// Formulation used to calculate the
+/// saturation vapour pressure
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VapourPressureFormula {
+    /// Hardy (1998) ITS-90 formulation, as used by
+    /// the UTCI reference implementation
+    #[default]
+    Hardy,
+    /// Magnus-Tetens approximation
+    MagnusTetens,
+    /// Buck (1996) formulation
+    Buck,
+    /// Goff-Gratch (1946) formulation
+    GoffGratch,
+}
+
+/// Surface over which the air is saturated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SaturationPhase {
+    /// Saturation over liquid water
+    #[default]
+    Water,
+    /// Saturation over ice
+    Ice,
+}
+
+/// Used to calculate the saturation vapour
+/// pressure at the given air temperature [C]
+pub fn saturation_vapour_pressure(
+    air_temperature: f64,
+    formula: VapourPressureFormula,
+    phase: SaturationPhase,
+) -> Hectopascal {
+    let pressure = match (formula, phase) {
+        (VapourPressureFormula::Hardy, SaturationPhase::Water) => {
+            hardy_water(air_temperature)
+        }
+        (VapourPressureFormula::Hardy, SaturationPhase::Ice) => {
+            hardy_ice(air_temperature)
+        }
+        (VapourPressureFormula::MagnusTetens, SaturationPhase::Water) => {
+            6.1078
+                * (17.27 * air_temperature / (air_temperature + 237.3))
+                    .exp()
+        }
+        (VapourPressureFormula::MagnusTetens, SaturationPhase::Ice) => {
+            6.1078
+                * (21.875 * air_temperature / (air_temperature + 265.5))
+                    .exp()
+        }
+        (VapourPressureFormula::Buck, SaturationPhase::Water) => {
+            6.1121
+                * ((18.678 - air_temperature / 234.5)
+                    * (air_temperature / (257.14 + air_temperature)))
+                    .exp()
+        }
+        (VapourPressureFormula::Buck, SaturationPhase::Ice) => {
+            6.1115
+                * ((23.036 - air_temperature / 333.7)
+                    * (air_temperature / (279.82 + air_temperature)))
+                    .exp()
+        }
+        (VapourPressureFormula::GoffGratch, SaturationPhase::Water) => {
+            goff_gratch_water(air_temperature)
+        }
+        (VapourPressureFormula::GoffGratch, SaturationPhase::Ice) => {
+            goff_gratch_ice(air_temperature)
+        }
+    };
+    Hectopascal(pressure)
+}
+
+/// Used to calculate the vapour pressure in
+/// the air based on air temperature [C] and
+/// relative humidity [%]
+pub fn vapour_pressure(
+    air_temperature: f64,
+    relative_humidity: f64,
+    formula: VapourPressureFormula,
+    phase: SaturationPhase,
+) -> Hectopascal {
+    let saturation =
+        saturation_vapour_pressure(air_temperature, formula, phase);
+    Hectopascal(saturation.0 * relative_humidity / 100.0)
+}
+
+// Function to calculate the ITS-90 saturation
+// vapour pressure over water in hPa
+fn hardy_water(air_temperature: f64) -> f64 {
+    let g = [
+        -2.8365744e3,
+        -6.028076559e3,
+        1.954263612e1,
+        -2.737830188e-2,
+        1.6261698e-5,
+        7.0229056e-10,
+        -1.8680009e-13,
+    ];
+    let kelvin_temperature = air_temperature + 273.15;
+
+    let conversion = g.iter().enumerate().fold(
+        2.7150305 * kelvin_temperature.ln(),
+        |sum, (i, g)| sum + g * kelvin_temperature.powi(i as i32 - 2),
+    );
+
+    conversion.exp() * 0.01
+}
+
+// Function to calculate the ITS-90 saturation
+// vapour pressure over ice in hPa
+fn hardy_ice(air_temperature: f64) -> f64 {
+    let k = [
+        -5.8666426e3,
+        2.232870244e1,
+        1.39387003e-2,
+        -3.4262402e-5,
+        2.7040955e-8,
+    ];
+    let kelvin_temperature = air_temperature + 273.15;
+
+    let conversion = k.iter().enumerate().fold(
+        6.7063522e-1 * kelvin_temperature.ln(),
+        |sum, (i, k)| sum + k * kelvin_temperature.powi(i as i32 - 1),
+    );
+
+    conversion.exp() * 0.01
+}
+
+// Function to calculate the Goff-Gratch
+// saturation vapour pressure over water in hPa
+fn goff_gratch_water(air_temperature: f64) -> f64 {
+    // steam point temperature and pressure
+    let steam_temperature = 373.15;
+    let steam_pressure = 1013.246f64;
+    let ratio = steam_temperature / (air_temperature + 273.15);
+
+    let log_pressure = -7.90298 * (ratio - 1.0)
+        + 5.02808 * ratio.log10()
+        - 1.3816e-7 * (10f64.powf(11.344 * (1.0 - 1.0 / ratio)) - 1.0)
+        + 8.1328e-3 * (10f64.powf(-3.49149 * (ratio - 1.0)) - 1.0)
+        + steam_pressure.log10();
+
+    10f64.powf(log_pressure)
+}
+
+// Function to calculate the Goff-Gratch
+// saturation vapour pressure over ice in hPa
+fn goff_gratch_ice(air_temperature: f64) -> f64 {
+    // triple point temperature and pressure
+    let triple_temperature = 273.16;
+    let triple_pressure = 6.1173f64;
+    let ratio = triple_temperature / (air_temperature + 273.15);
+
+    let log_pressure = -9.09718 * (ratio - 1.0)
+        - 3.56654 * ratio.log10()
+        + 0.876793 * (1.0 - 1.0 / ratio)
+        + triple_pressure.log10();
+
+    10f64.powf(log_pressure)
+}
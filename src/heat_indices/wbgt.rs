@@ -0,0 +1,419 @@
+//! Module containing the outdoor Wet Bulb Globe
+//! Temperature following Liljegren et al. (2008)
+
+use std::f64::consts::PI;
+
+use super::utci::{
+    solar_radiation,
+    validate_observation,
+    Observation,
+    CANOPY_TRANSMISSIVITY,
+};
+use super::vapour_pressure::{
+    saturation_vapour_pressure,
+    SaturationPhase,
+    VapourPressureFormula,
+};
+use crate::error::Error;
+use crate::site::Site;
+use crate::solar::SolarPosition;
+
+// Stefan-Boltzmann constant [W/m2/K4]
+const STEFAN_BOLTZMANN: f64 = 5.6696e-8;
+
+// Pressure of the air at sea level [hPa]
+const AIR_PRESSURE: f64 = 1013.25;
+
+// Specific heat of dry air [J/kg/K]
+const SPECIFIC_HEAT: f64 = 1003.5;
+
+// Molecular weights of dry air and water
+// vapour [kg/kmol]
+const MOLAR_MASS_AIR: f64 = 28.97;
+const MOLAR_MASS_WATER: f64 = 18.015;
+
+// Universal gas constant [J/kmol/K]
+const GAS_CONSTANT: f64 = 8314.34;
+
+// Properties of the black globe, its diameter
+// being that of the standard 2 inch globe [m]
+const GLOBE_DIAMETER: f64 = 0.0508;
+const GLOBE_EMISSIVITY: f64 = 0.95;
+const GLOBE_ALBEDO: f64 = 0.05;
+
+// Properties of the wick of the natural wet
+// bulb thermometer [m]
+const WICK_DIAMETER: f64 = 0.007;
+const WICK_LENGTH: f64 = 0.0254;
+const WICK_EMISSIVITY: f64 = 0.95;
+const WICK_ALBEDO: f64 = 0.4;
+
+// Properties of the ground below
+const SURFACE_EMISSIVITY: f64 = 0.999;
+const SURFACE_ALBEDO: f64 = 0.45;
+
+// Lowest wind speed of the convection [m/s]
+const MIN_WIND_SPEED: f64 = 0.13;
+
+// Heights of the wind measurement and of the
+// instruments [m]
+const WIND_HEIGHT: f64 = 10.0;
+const INSTRUMENT_HEIGHT: f64 = 2.0;
+
+// Exponents of the power law wind profile over
+// urban terrain for the stability classes A to F
+const URBAN_EXPONENTS: [f64; 6] = [0.15, 0.15, 0.20, 0.25, 0.30, 0.30];
+
+// Stability classes (A = 0) by day for the wind
+// speed classes (< 2, 3, 5, 6 and over 6 m/s) and
+// strong, moderate, slight and weak sunlight
+const DAY_STABILITY: [[usize; 4]; 5] = [
+    [0, 0, 1, 3],
+    [0, 1, 2, 3],
+    [1, 1, 2, 3],
+    [2, 2, 3, 3],
+    [2, 3, 3, 3],
+];
+
+// Stability classes at night for the wind
+// speed classes, with the temperature falling
+// with height
+const NIGHT_STABILITY: [usize; 5] = [4, 4, 3, 3, 3];
+
+// Cosine of the zenith below which the direct
+// beam is left out
+const MIN_COS_ZENITH: f64 = 0.00873;
+
+// Change of the temperature below which
+// the iteration has converged [K]
+const CONVERGENCE: f64 = 0.02;
+const MAX_ITERATIONS: usize = 50;
+
+/// Method for calculating the outdoor Wet
+/// Bulb Globe Temperature [C] in the city of
+/// Groningen
+pub fn wet_bulb_globe_temperature(
+    observation: Observation,
+) -> Result<f32, Error> {
+    let site = Site::groningen(observation.binnenstad, observation.park);
+    wet_bulb_globe_temperature_at_site(observation, &site)
+}
+
+/// Method for calculating the outdoor Wet
+/// Bulb Globe Temperature [C] at the given site
+///
+/// The globe and natural wet bulb temperatures
+/// are solved from their energy balance in the
+/// cloudless sunlight of the site, following
+/// Liljegren et al. (2008), and weighted as
+/// 0.7 Tnwb + 0.2 Tg + 0.1 Ta. The wind speed at
+/// 10 m is reduced to the 2 m of the instruments
+/// with the power law of Liljegren for urban
+/// terrain. An energy balance which does not
+/// converge is reported as an `Error`.
+pub fn wet_bulb_globe_temperature_at_site(
+    observation: Observation,
+    site: &Site,
+) -> Result<f32, Error> {
+    wet_bulb_globe_temperature_with_radiation(observation, site, None)
+}
+
+/// Method for calculating the outdoor Wet Bulb
+/// Globe Temperature [C] at the given site under
+/// a measured global radiation [W/m2], or under a
+/// cloudless sky without a measurement
+pub fn wet_bulb_globe_temperature_with_radiation(
+    observation: Observation,
+    site: &Site,
+    global_radiation: Option<f32>,
+) -> Result<f32, Error> {
+    validate_observation(
+        observation.air_temperature,
+        observation.wind_speed,
+        observation.relative_humidity,
+    )?;
+    site.validate()?;
+
+    let position =
+        SolarPosition::at(observation.timestamp, site.latitude, site.longitude);
+    let radiation = solar_radiation(&position, global_radiation)?;
+    let beam = if !site.canyon.is_sunlit(&position) {
+        0.0
+    } else if observation.shade {
+        f64::from(CANOPY_TRANSMISSIVITY)
+    } else {
+        1.0
+    };
+    let direct = radiation.direct_horizontal() * beam;
+    let sunlight = Sunlight {
+        global: direct + radiation.diffuse_horizontal,
+        direct,
+        cos_zenith: radiation.cos_zenith,
+    };
+
+    let air = Air {
+        temperature: f64::from(observation.air_temperature) + 273.15,
+        relative_humidity: f64::from(observation.relative_humidity) / 100.0,
+        wind_speed: instrument_wind_speed(
+            f64::from(observation.wind_speed),
+            radiation.direct_horizontal() + radiation.diffuse_horizontal,
+            radiation.cos_zenith,
+        ),
+    };
+    let globe = globe_temperature(&air, &sunlight)?;
+    let wet_bulb = natural_wet_bulb_temperature(&air, &sunlight)?;
+
+    let output =
+        (0.7 * wet_bulb + 0.2 * globe + 0.1 * air.temperature - 273.15) as f32;
+    Ok(output)
+}
+
+// Air around the instruments
+struct Air {
+    // temperature [K]
+    temperature: f64,
+    // relative humidity (0, 1)
+    relative_humidity: f64,
+    // wind speed [m/s]
+    wind_speed: f64,
+}
+
+impl Air {
+    // Function to calculate the vapour
+    // pressure of the air [hPa]
+    fn vapour_pressure(&self) -> f64 {
+        self.relative_humidity * saturation(self.temperature)
+    }
+
+    // Function to calculate the emissivity
+    // of the atmosphere
+    fn emissivity(&self) -> f64 {
+        0.575 * self.vapour_pressure().powf(1.0 / 7.0)
+    }
+
+    // Function to calculate the long wave
+    // radiation of the sky and the ground,
+    // with the ground at air temperature
+    fn long_wave(&self) -> f64 {
+        0.5 * (self.emissivity() + SURFACE_EMISSIVITY)
+            * self.temperature.powi(4)
+    }
+}
+
+// Sunlight reaching the instruments [W/m2]
+struct Sunlight {
+    global: f64,
+    direct: f64,
+    cos_zenith: f64,
+}
+
+impl Sunlight {
+    // Function to find the fraction of the
+    // sunlight in the direct beam
+    fn direct_fraction(&self) -> f64 {
+        if self.global > 0.0 && self.cos_zenith > MIN_COS_ZENITH {
+            (self.direct / self.global).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+}
+
+// Function to reduce the wind speed at 10 m
+// to the height of the instruments [m/s], with
+// the stability of the unshaded global radiation
+fn instrument_wind_speed(wind_speed: f64, global: f64, cos_zenith: f64) -> f64 {
+    let class = stability_class(wind_speed, global, cos_zenith);
+    let exponent = URBAN_EXPONENTS[class];
+    let reduced = wind_speed * (INSTRUMENT_HEIGHT / WIND_HEIGHT).powf(exponent);
+    reduced.max(MIN_WIND_SPEED)
+}
+
+// Function to find the Pasquill stability class
+// (A = 0) from the wind speed [m/s] and the
+// global radiation [W/m2]
+fn stability_class(wind_speed: f64, global: f64, cos_zenith: f64) -> usize {
+    let speed = if wind_speed < 2.0 {
+        0
+    } else if wind_speed < 3.0 {
+        1
+    } else if wind_speed < 5.0 {
+        2
+    } else if wind_speed < 6.0 {
+        3
+    } else {
+        4
+    };
+    if cos_zenith <= 0.0 {
+        return NIGHT_STABILITY[speed];
+    }
+    let strength = if global >= 925.0 {
+        0
+    } else if global >= 675.0 {
+        1
+    } else if global >= 175.0 {
+        2
+    } else {
+        3
+    };
+    DAY_STABILITY[speed][strength]
+}
+
+// Function to solve the temperature of the
+// black globe [K]
+fn globe_temperature(air: &Air, sunlight: &Sunlight) -> Result<f64, Error> {
+    let direct = sunlight.direct_fraction();
+    let cos_zenith = sunlight.cos_zenith.max(MIN_COS_ZENITH);
+    let absorbed = sunlight.global
+        / (2.0 * GLOBE_EMISSIVITY * STEFAN_BOLTZMANN)
+        * (1.0 - GLOBE_ALBEDO)
+        * (direct * (1.0 / (2.0 * cos_zenith) - 1.0) + 1.0 + SURFACE_ALBEDO);
+
+    let mut previous = air.temperature;
+    for _ in 0..MAX_ITERATIONS {
+        let film = 0.5 * (previous + air.temperature);
+        let convection = sphere_convection(film, air.wind_speed);
+        let globe = (air.long_wave()
+            - convection / (GLOBE_EMISSIVITY * STEFAN_BOLTZMANN)
+                * (previous - air.temperature)
+            + absorbed)
+            .powf(0.25);
+        if (globe - previous).abs() < CONVERGENCE {
+            return Ok(globe);
+        }
+        previous = 0.9 * previous + 0.1 * globe;
+    }
+    Err(Error::NoConvergence)
+}
+
+// Function to solve the temperature of the
+// natural wet bulb [K]
+fn natural_wet_bulb_temperature(
+    air: &Air,
+    sunlight: &Sunlight,
+) -> Result<f64, Error> {
+    let direct = sunlight.direct_fraction();
+    let zenith = sunlight.cos_zenith.max(MIN_COS_ZENITH).acos();
+    let shape = 0.25 * WICK_DIAMETER / WICK_LENGTH;
+    let absorbed = (1.0 - WICK_ALBEDO)
+        * sunlight.global
+        * ((1.0 - direct) * (1.0 + shape)
+            + direct * (zenith.tan() / PI + shape)
+            + SURFACE_ALBEDO);
+    let air_pressure = air.vapour_pressure();
+    let ratio = SPECIFIC_HEAT * MOLAR_MASS_AIR / MOLAR_MASS_WATER;
+
+    let mut previous = dew_point(air_pressure);
+    for _ in 0..MAX_ITERATIONS {
+        let film = 0.5 * (previous + air.temperature);
+        let convection = cylinder_convection(film, air.wind_speed);
+        let radiation = STEFAN_BOLTZMANN
+            * WICK_EMISSIVITY
+            * (air.long_wave() - previous.powi(4))
+            + absorbed;
+        let wick_pressure = saturation(previous);
+        let density = AIR_PRESSURE * 100.0 / (air_constant() * film);
+        let schmidt = viscosity(film) / (density * diffusivity(film));
+        let wet_bulb = air.temperature
+            - evaporation_heat(film) / ratio * (wick_pressure - air_pressure)
+                / (AIR_PRESSURE - wick_pressure)
+                * (prandtl() / schmidt).powf(0.56)
+            + radiation / convection;
+        if (wet_bulb - previous).abs() < CONVERGENCE {
+            return Ok(wet_bulb);
+        }
+        previous = 0.9 * previous + 0.1 * wet_bulb;
+    }
+    Err(Error::NoConvergence)
+}
+
+// Function to calculate the heat transfer
+// coefficient of a sphere [W/m2/K]
+fn sphere_convection(temperature: f64, wind_speed: f64) -> f64 {
+    let reynolds = reynolds(temperature, wind_speed, GLOBE_DIAMETER);
+    let nusselt = 2.0 + 0.6 * reynolds.sqrt() * prandtl().powf(1.0 / 3.0);
+    nusselt * conductivity(temperature) / GLOBE_DIAMETER
+}
+
+// Function to calculate the heat transfer
+// coefficient of a cylinder in cross flow
+// [W/m2/K]
+fn cylinder_convection(temperature: f64, wind_speed: f64) -> f64 {
+    let reynolds = reynolds(temperature, wind_speed, WICK_DIAMETER);
+    let nusselt = 0.281 * reynolds.powf(0.6) * prandtl().powf(0.44);
+    nusselt * conductivity(temperature) / WICK_DIAMETER
+}
+
+// Function to calculate the Reynolds number
+// of the flow around a body
+fn reynolds(temperature: f64, wind_speed: f64, diameter: f64) -> f64 {
+    let density = AIR_PRESSURE * 100.0 / (air_constant() * temperature);
+    wind_speed * density * diameter / viscosity(temperature)
+}
+
+// Function to calculate the gas constant
+// of dry air [J/kg/K]
+fn air_constant() -> f64 {
+    GAS_CONSTANT / MOLAR_MASS_AIR
+}
+
+// Function to calculate the Prandtl number
+// of the air
+fn prandtl() -> f64 {
+    SPECIFIC_HEAT / (SPECIFIC_HEAT + 1.25 * air_constant())
+}
+
+// Function to calculate the thermal
+// conductivity of the air [W/m/K]
+fn conductivity(temperature: f64) -> f64 {
+    (SPECIFIC_HEAT + 1.25 * air_constant()) * viscosity(temperature)
+}
+
+// Function to calculate the viscosity of
+// the air [kg/m/s]
+fn viscosity(temperature: f64) -> f64 {
+    let sigma = 3.617;
+    let omega = (temperature / 97.0 - 2.9) / 0.4 * -0.034 + 1.048;
+    2.6693e-6 * (MOLAR_MASS_AIR * temperature).sqrt() / (sigma * sigma * omega)
+}
+
+// Function to calculate the diffusivity of
+// water vapour in air [m2/s]
+fn diffusivity(temperature: f64) -> f64 {
+    let critical_pressure = (36.4 * 218.0f64).powf(1.0 / 3.0);
+    let critical_temperature = (132.0 * 647.3f64).powf(5.0 / 12.0);
+    let mean_critical = (132.0 * 647.3f64).sqrt();
+    let molar_mass = (1.0 / MOLAR_MASS_AIR + 1.0 / MOLAR_MASS_WATER).sqrt();
+    3.64e-4
+        * (temperature / mean_critical).powf(2.334)
+        * critical_pressure
+        * critical_temperature
+        * molar_mass
+        / (AIR_PRESSURE / 1013.25)
+        * 1e-4
+}
+
+// Function to calculate the heat of
+// evaporation of water [J/kg]
+fn evaporation_heat(temperature: f64) -> f64 {
+    (313.15 - temperature) / 30.0 * -71100.0 + 2.4073e6
+}
+
+// Function to calculate the saturation
+// vapour pressure over water [hPa]
+fn saturation(temperature: f64) -> f64 {
+    saturation_vapour_pressure(
+        temperature - 273.15,
+        VapourPressureFormula::Buck,
+        SaturationPhase::Water,
+    )
+    .0
+}
+
+// Function to estimate the dew point from
+// the vapour pressure [K], as the start of
+// the wet bulb iteration
+fn dew_point(pressure: f64) -> f64 {
+    let ln = (pressure.max(0.01) / 6.1121).ln();
+    257.14 * ln / (18.678 - ln) + 273.15
+}
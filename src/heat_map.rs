@@ -0,0 +1,322 @@
+//! Module containing the mapping of experienced
+//! Heat Stress over rasters of land cover and
+//! surface heights
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::canyon::CanyonGeometry;
+use crate::error::Error;
+use crate::heat_indices::{
+    solar_radiation, utci_at_site, validate_observation, Sun,
+    CANOPY_TRANSMISSIVITY,
+};
+use crate::materials::{Material, Surfaces};
+use crate::raster::Raster;
+use crate::shadow::{shadow_at, Shading};
+use crate::site::Site;
+use crate::sky_view::{scan_sky_view_factors, validate_scan, HorizonScan};
+use crate::solar::{SolarPosition, SolarRadiation};
+
+/// Kind of surface covering a pixel, with the
+/// code written to the surface raster
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+#[repr(u8)]
+pub enum SurfaceType {
+    /// Roof of a building
+    Building = 1,
+    /// Paved street or square
+    Paved = 2,
+    /// Grass or other low vegetation
+    Grass = 3,
+    /// Open water
+    Water = 4,
+    /// Ground under the canopy of a tree
+    Tree = 5,
+}
+
+impl SurfaceType {
+    /// Every surface type, in the order
+    /// of their codes
+    pub const ALL: [SurfaceType; 5] = [
+        SurfaceType::Building,
+        SurfaceType::Paved,
+        SurfaceType::Grass,
+        SurfaceType::Water,
+        SurfaceType::Tree,
+    ];
+
+    /// Material of the ground of the surface
+    pub fn ground(self) -> Material {
+        match self {
+            SurfaceType::Building => Material::CONCRETE,
+            SurfaceType::Paved => Material::KLINKERS,
+            SurfaceType::Grass | SurfaceType::Tree => Material::GRASS,
+            SurfaceType::Water => Material::WATER,
+        }
+    }
+}
+
+/// Weather over the whole map
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Weather {
+    /// Air temperature [C]
+    pub air_temperature: f32,
+    /// Wind speed [m/s]
+    pub wind_speed: f32,
+    /// Relative humidity [%]
+    pub relative_humidity: f32,
+    /// Moment of the observation
+    pub timestamp: DateTime<Utc>,
+    /// Measured global radiation [W/m2], or
+    /// none for a cloudless sky
+    pub global_radiation: Option<f32>,
+}
+
+/// Settings of the heat stress map
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeatMapSettings {
+    /// Surface type of every class of the
+    /// land cover raster
+    pub classes: BTreeMap<u8, SurfaceType>,
+    /// Scan over the horizon of every pixel,
+    /// also bounding the search for buildings
+    /// and trees casting shadows
+    pub horizon: HorizonScan,
+    /// Latitude of the map [degrees]
+    pub latitude: f64,
+    /// Longitude of the map [degrees]
+    pub longitude: f64,
+}
+
+impl Default for HeatMapSettings {
+    fn default() -> HeatMapSettings {
+        HeatMapSettings {
+            classes: SurfaceType::ALL
+                .iter()
+                .map(|surface| (*surface as u8, *surface))
+                .collect(),
+            horizon: HorizonScan::default(),
+            latitude: Site::GRONINGEN_LATITUDE,
+            longitude: Site::GRONINGEN_LONGITUDE,
+        }
+    }
+}
+
+/// Rasters derived for every pixel of the map
+///
+/// Roofs and classes without a surface type
+/// have no sky view factor or UTCI (NaN) and a
+/// surface code of 0.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeatMap {
+    /// Sky View Factor (0, 1)
+    pub sky_view_factor: Raster<f32>,
+    /// Code of the `Shading` of the pixel
+    pub shading: Raster<u8>,
+    /// Code of the `SurfaceType` of the pixel
+    pub surface: Raster<u8>,
+    /// UTCI equivalent temperature [C]
+    pub utci: Raster<f32>,
+}
+
+// Terms of the map shared by every pixel
+struct MapContext<'a> {
+    surface: Vec<Option<SurfaceType>>,
+    buildings: Raster<f32>,
+    vegetation: Raster<f32>,
+    weather: &'a Weather,
+    settings: &'a HeatMapSettings,
+    position: SolarPosition,
+    radiation: SolarRadiation,
+}
+
+// Terms derived for a single pixel
+#[derive(Clone, Copy)]
+struct Pixel {
+    sky_view_factor: f32,
+    shading: Shading,
+    surface: Option<SurfaceType>,
+    utci: f32,
+}
+
+/// Method for mapping experienced Heat Stress
+/// from a land cover raster, the heights above
+/// the ground of buildings and trees [m], and
+/// the weather over the map
+///
+/// The sky view factor of every pixel is
+/// scanned over the horizon of the buildings
+/// and trees, and its shading follows from
+/// the shadows they cast. Pixels under a tree
+/// or in its shadow are taken as shaded by
+/// a tree.
+/// Rasters which are not aligned, or weather
+/// outside of the supported ranges, are
+/// reported as an `Error`.
+pub fn heat_stress_map(
+    land_cover: &Raster<u8>,
+    heights: &Raster<f32>,
+    weather: &Weather,
+    settings: &HeatMapSettings,
+) -> Result<HeatMap, Error> {
+    validate_observation(
+        weather.air_temperature,
+        weather.wind_speed,
+        weather.relative_humidity,
+    )?;
+    if !land_cover.is_aligned(heights) {
+        return Err(Error::InvalidInput);
+    }
+    validate_scan(heights, &settings.horizon)?;
+
+    let position = SolarPosition::at(
+        weather.timestamp,
+        settings.latitude,
+        settings.longitude,
+    );
+    let surface: Vec<Option<SurfaceType>> = land_cover
+        .data()
+        .iter()
+        .map(|class| settings.classes.get(class).copied())
+        .collect();
+    // heights of a single kind of surface,
+    // the rest being open ground
+    let heights_of = |kind: SurfaceType| {
+        let data = surface
+            .iter()
+            .zip(heights.data())
+            .map(|(surface, height)| match surface {
+                Some(surface) if *surface == kind => height.max(0.0),
+                _ => 0.0,
+            })
+            .collect();
+        heights.with_data(data)
+    };
+    let context = MapContext {
+        buildings: heights_of(SurfaceType::Building)?,
+        vegetation: heights_of(SurfaceType::Tree)?,
+        surface,
+        weather,
+        settings,
+        radiation: solar_radiation(&position, weather.global_radiation)?,
+        position,
+    };
+
+    let len = land_cover.data().len();
+    #[cfg(feature = "rayon")]
+    let pixels: Vec<Pixel> = (0..len)
+        .into_par_iter()
+        .map(|index| evaluate_pixel(&context, index))
+        .collect();
+    #[cfg(not(feature = "rayon"))]
+    let pixels: Vec<Pixel> = (0..len)
+        .map(|index| evaluate_pixel(&context, index))
+        .collect();
+
+    let output = HeatMap {
+        sky_view_factor: heights.with_data(
+            pixels.iter().map(|pixel| pixel.sky_view_factor).collect(),
+        )?,
+        shading: heights.with_data(
+            pixels.iter().map(|pixel| pixel.shading as u8).collect(),
+        )?,
+        surface: heights.with_data(
+            pixels
+                .iter()
+                .map(|pixel| pixel.surface.map_or(0, |surface| surface as u8))
+                .collect(),
+        )?,
+        utci: heights
+            .with_data(pixels.iter().map(|pixel| pixel.utci).collect())?,
+    };
+    Ok(output)
+}
+
+// Function to derive the geometry and UTCI
+// of a single pixel
+fn evaluate_pixel(context: &MapContext, index: usize) -> Pixel {
+    let width = context.buildings.width();
+    let (column, row) = (index % width, index / width);
+    let surface = context.surface[index];
+    let open = match surface {
+        None | Some(SurfaceType::Building) => {
+            return Pixel {
+                sky_view_factor: f32::NAN,
+                shading: Shading::Sunlit,
+                surface,
+                utci: f32::NAN,
+            }
+        }
+        Some(surface) => surface,
+    };
+
+    // the canopy overhead is taken into account
+    // by the shade of the tree itself
+    let sky_view_factor = scan_sky_view_factors(
+        &context.buildings,
+        &context.vegetation,
+        column,
+        row,
+        &context.settings.horizon,
+    )
+    .combined(CANOPY_TRANSMISSIVITY);
+
+    let shading = shadow_at(
+        &context.buildings,
+        &context.vegetation,
+        column,
+        row,
+        &context.position,
+        context.settings.horizon.search_distance,
+    );
+    // the sun filtered by the canopy still
+    // reaches the shade of a tree
+    let under_tree = open == SurfaceType::Tree || shading == Shading::Tree;
+    let sunlit = shading != Shading::Building;
+
+    let site = Site {
+        canyon: CanyonGeometry::GRONINGEN_RESIDENTIAL,
+        surfaces: Surfaces {
+            ground: open.ground(),
+            walls: Material::BRICK,
+            canopy: Material::TREE_CANOPY,
+        },
+        latitude: context.settings.latitude,
+        longitude: context.settings.longitude,
+    };
+    let sun = Sun::new(context.radiation, context.position, sunlit);
+    let utci = utci_at_site(
+        context.weather.air_temperature,
+        context.weather.wind_speed,
+        context.weather.relative_humidity,
+        &site,
+        sky_view_factor,
+        under_tree,
+        &sun,
+    )
+    .map_or(f32::NAN, |heat_stress| heat_stress.utci);
+
+    Pixel {
+        sky_view_factor,
+        shading,
+        surface,
+        utci,
+    }
+}
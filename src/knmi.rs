@@ -0,0 +1,202 @@
+//! Module containing the reader of KNMI hourly
+//! station data, such as that of Eelde
+
+use std::convert::TryFrom;
+use std::io::BufRead;
+
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, TimeZone, Utc};
+
+use crate::error::Error;
+use crate::heat_indices::{heat_stress_at_site, HeatStress, Observation};
+use crate::series::RowError;
+use crate::site::Site;
+
+/// Station number of Groningen Airport Eelde
+pub const KNMI_EELDE: u32 = 280;
+
+/// Hour of weather observed at a KNMI station,
+/// converted to SI units
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KnmiRecord {
+    /// Station number (STN)
+    pub station: u32,
+    /// End of the hour of observation
+    pub timestamp: DateTime<Utc>,
+    /// Air temperature at 1.5 m (T) [C]
+    pub air_temperature: Option<f32>,
+    /// Hourly mean wind speed (FH) [m/s]
+    pub wind_speed: Option<f32>,
+    /// Relative humidity at 1.5 m (U) [%]
+    pub relative_humidity: Option<f32>,
+    /// Mean global radiation over the hour (Q) [W/m2]
+    pub global_radiation: Option<f32>,
+}
+
+impl KnmiRecord {
+    /// Observation of the hour at the
+    /// given location
+    pub fn observation(
+        &self,
+        binnenstad: bool,
+        park: bool,
+        shade: bool,
+    ) -> Result<Observation, Error> {
+        let require = |value: Option<f32>, column: &str| {
+            value.ok_or_else(|| Error::MissingValue(column.to_string()))
+        };
+        Ok(Observation {
+            air_temperature: require(self.air_temperature, "T")?,
+            wind_speed: require(self.wind_speed, "FH")?,
+            relative_humidity: require(self.relative_humidity, "U")?,
+            binnenstad,
+            park,
+            shade,
+            timestamp: self.timestamp,
+        })
+    }
+
+    /// Experienced Heat Stress of the hour at the
+    /// given location, under the measured global
+    /// radiation or a cloudless sky without Q
+    pub fn heat_stress(
+        &self,
+        binnenstad: bool,
+        park: bool,
+        shade: bool,
+    ) -> Result<HeatStress, Error> {
+        heat_stress_at_site(
+            self.observation(binnenstad, park, shade)?,
+            &Site::groningen(binnenstad, park),
+            self.global_radiation,
+        )
+    }
+}
+
+// Positions of the columns within a row
+struct Columns {
+    station: usize,
+    date: usize,
+    hour: usize,
+    air_temperature: usize,
+    wind_speed: usize,
+    relative_humidity: usize,
+    global_radiation: Option<usize>,
+}
+
+impl Columns {
+    // Function to find the columns in the
+    // commented header naming them
+    fn from_header(header: &str) -> Result<Columns, Error> {
+        let names: Vec<&str> = header
+            .trim_start_matches('#')
+            .split(',')
+            .map(str::trim)
+            .collect();
+        let find = |name: &str| names.iter().position(|column| *column == name);
+        let require = |name: &str| {
+            find(name).ok_or_else(|| Error::MissingColumn(name.to_string()))
+        };
+        Ok(Columns {
+            station: require("STN")?,
+            date: require("YYYYMMDD")?,
+            hour: require("HH")?,
+            air_temperature: require("T")?,
+            wind_speed: require("FH")?,
+            relative_humidity: require("U")?,
+            global_radiation: find("Q"),
+        })
+    }
+
+    // Function to read a row of data
+    fn record(&self, line: &str) -> Result<KnmiRecord, Error> {
+        let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+        let field = |index: usize| fields.get(index).copied().unwrap_or("");
+        let integer = |index: usize| -> Result<Option<i64>, Error> {
+            match field(index) {
+                "" => Ok(None),
+                value => {
+                    value.parse().map(Some).map_err(|_| Error::InvalidInput)
+                }
+            }
+        };
+
+        let station = integer(self.station)?
+            .and_then(|station| u32::try_from(station).ok())
+            .ok_or_else(|| Error::MissingValue(String::from("STN")))?;
+        let date = NaiveDate::parse_from_str(field(self.date), "%Y%m%d")
+            .map_err(|_| Error::InvalidTimestamp)?;
+        let hour = integer(self.hour)?
+            .filter(|hour| (1..=24).contains(hour))
+            .ok_or(Error::InvalidTimestamp)?;
+        // hours run from 1 to 24 and mark the end
+        // of the hour of observation in UT
+        let timestamp = Utc.from_utc_datetime(&date.and_time(NaiveTime::MIN))
+            + Duration::hours(hour);
+
+        // temperature and wind are given in tenths,
+        // radiation in J/cm2 over the hour
+        let tenths = |index: usize| -> Result<Option<f32>, Error> {
+            Ok(integer(index)?.map(|value| value as f32 / 10.0))
+        };
+        let global_radiation = match self.global_radiation {
+            Some(index) => {
+                integer(index)?.map(|value| value as f32 * 10_000.0 / 3600.0)
+            }
+            None => None,
+        };
+
+        Ok(KnmiRecord {
+            station,
+            timestamp,
+            air_temperature: tenths(self.air_temperature)?,
+            wind_speed: tenths(self.wind_speed)?,
+            relative_humidity: integer(self.relative_humidity)?
+                .map(|value| value as f32),
+            global_radiation,
+        })
+    }
+}
+
+/// Method for reading a KNMI hourly station
+/// data file (uurgegevens)
+///
+/// The columns are found from the commented
+/// `# STN,YYYYMMDD,HH,...` header, skipping the
+/// preamble above it. A file without the STN,
+/// YYYYMMDD, HH, T, FH and U columns is reported
+/// as an `Error`, a row which cannot be read as
+/// a `RowError` in its place.
+pub fn read_knmi_hourly<R: BufRead>(
+    input: R,
+) -> Result<Vec<Result<KnmiRecord, RowError>>, Error> {
+    let mut columns: Option<Columns> = None;
+    let mut records = Vec::new();
+
+    for (index, line) in input.lines().enumerate() {
+        let line = line.map_err(|error| Error::CsvFile(error.to_string()))?;
+        let line_number = index as u64 + 1;
+        let trimmed = line.trim();
+
+        if trimmed.starts_with('#') {
+            let header = trimmed.trim_start_matches('#').trim_start();
+            if header.starts_with("STN,") {
+                columns = Some(Columns::from_header(header)?);
+            }
+            continue;
+        }
+        // the preamble before the header is
+        // free text describing the columns
+        let columns = match columns.as_ref() {
+            Some(columns) if !trimmed.is_empty() => columns,
+            _ => continue,
+        };
+        records.push(columns.record(trimmed).map_err(|error| RowError {
+            line: line_number,
+            error,
+        }));
+    }
+    if columns.is_none() {
+        return Err(Error::MissingColumn(String::from("STN")));
+    }
+    Ok(records)
+}
@@ -1,2 +1,104 @@
+mod canyon;
 mod error;
-mod heat_indices;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "geotiff")]
+pub mod geotiff;
+#[cfg(feature = "netcdf")]
+pub mod gridded;
+pub mod heat_indices;
+pub mod heat_map;
+pub mod knmi;
+mod materials;
+#[cfg(feature = "python")]
+mod python;
+pub mod radiation;
+pub mod raster;
+pub mod series;
+mod service;
+pub mod shadow;
+mod site;
+pub mod sky_view;
+mod solar;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+pub use canyon::CanyonGeometry;
+pub use error::Error;
+#[cfg(feature = "geotiff")]
+pub use geotiff::{
+    process_geotiff,
+    read_geotiff,
+    write_geotiff,
+    write_heat_map,
+};
+#[cfg(feature = "netcdf")]
+pub use gridded::{
+    process_netcdf,
+    read_grid,
+    write_utci_grid,
+    Grid,
+    GridMapping,
+};
+pub use heat_map::{
+    heat_stress_map,
+    HeatMap,
+    HeatMapSettings,
+    SurfaceType,
+    Weather,
+};
+pub use knmi::{read_knmi_hourly, KnmiRecord};
+pub use materials::{Material, MaterialLibrary, Surfaces};
+pub use radiation::{
+    mean_radiant_temperature,
+    Direction,
+    DirectionalFluxes,
+};
+pub use raster::{GeoTransform, Raster};
+pub use series::{annotate_csv, read_observations, ColumnMapping};
+pub use service::{CategoryOutput, HeatStressOutput, ObservationInput};
+pub use shadow::{
+    hourly_shadow_masks,
+    shadow_at,
+    shadow_mask,
+    ShadowMask,
+    Shading,
+};
+pub use site::Site;
+pub use sky_view::{
+    sky_view_factor,
+    sky_view_factor_raster,
+    sky_view_factors,
+    HorizonScan,
+    SkyViewFactor,
+};
+pub use solar::{SolarPosition, SolarRadiation};
+pub use heat_indices::{
+    apparent_temperature,
+    experienced_heat_stress,
+    experienced_heat_stress_batch,
+    experienced_heat_stress_at_site,
+    experienced_heat_stress_in_canyon,
+    experienced_personal_heat_stress,
+    experienced_pet,
+    heat_index,
+    humidex,
+    personal_heat_stress,
+    personal_heat_stress_with,
+    physiological_equivalent_temperature,
+    wet_bulb_globe_temperature,
+    wet_bulb_globe_temperature_at_site,
+    wet_bulb_globe_temperature_with_radiation,
+    AgeGroup,
+    HeatStress,
+    Location,
+    Observation,
+    ObservationColumns,
+    PersonalHeatStress,
+    PersonalProfile,
+    PetPerson,
+    RiskHeuristic,
+    RiskLevel,
+    Sex,
+    StressCategory,
+};
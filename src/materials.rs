@@ -0,0 +1,246 @@
+//! Module containing the radiative properties
+//! of urban surface materials
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+
+/// Radiative properties of a surface material
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Material {
+    /// Long wave emissivity (0, 1)
+    pub emissivity: f32,
+    /// Short wave albedo (0, 1)
+    pub albedo: f32,
+}
+
+impl Material {
+    /// Asphalt road surface
+    pub const ASPHALT: Material = Material {
+        emissivity: 0.95,
+        albedo: 0.08,
+    };
+    /// Brick facades
+    pub const BRICK: Material = Material {
+        emissivity: 0.9,
+        albedo: 0.3,
+    };
+    /// Brick street paving (dutch: klinkers)
+    pub const KLINKERS: Material = Material {
+        emissivity: 0.88,
+        albedo: 0.25,
+    };
+    /// Concrete surfaces
+    pub const CONCRETE: Material = Material {
+        emissivity: 0.94,
+        albedo: 0.3,
+    };
+    /// Grass lawns
+    pub const GRASS: Material = Material {
+        emissivity: 0.98,
+        albedo: 0.25,
+    };
+    /// Open water
+    pub const WATER: Material = Material {
+        emissivity: 0.96,
+        albedo: 0.07,
+    };
+    /// Canopy of deciduous trees
+    pub const TREE_CANOPY: Material = Material {
+        emissivity: 0.92,
+        albedo: 0.18,
+    };
+    /// Vegetated roofs
+    pub const GREEN_ROOF: Material = Material {
+        emissivity: 0.95,
+        albedo: 0.2,
+    };
+    /// Reflective pavement coatings
+    pub const COOL_PAVEMENT: Material = Material {
+        emissivity: 0.9,
+        albedo: 0.45,
+    };
+
+    /// Checks the emissivity and albedo
+    /// are within (0, 1)
+    pub fn validate(&self) -> Result<(), Error> {
+        if !(0.0..=1.0).contains(&self.emissivity)
+            || !(0.0..=1.0).contains(&self.albedo)
+        {
+            return Err(Error::InvalidMaterial);
+        }
+        Ok(())
+    }
+}
+
+/// Materials of the surfaces surrounding
+/// an observation
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Surfaces {
+    /// Material of the ground
+    pub ground: Material,
+    /// Material of the surrounding walls
+    pub walls: Material,
+    /// Material of the tree canopy overhead
+    pub canopy: Material,
+}
+
+impl Surfaces {
+    /// Paved streets lined with brick buildings
+    pub const GRONINGEN_URBAN: Surfaces = Surfaces {
+        ground: Material::KLINKERS,
+        walls: Material::BRICK,
+        canopy: Material::TREE_CANOPY,
+    };
+
+    /// Lawns surrounded by trees
+    pub const GRONINGEN_PARK: Surfaces = Surfaces {
+        ground: Material::GRASS,
+        walls: Material::TREE_CANOPY,
+        canopy: Material::TREE_CANOPY,
+    };
+
+    /// Preset of Groningen for parks or
+    /// the streets
+    pub fn groningen(park: bool) -> Surfaces {
+        if park {
+            Surfaces::GRONINGEN_PARK
+        } else {
+            Surfaces::GRONINGEN_URBAN
+        }
+    }
+
+    /// Materials of the ground, walls and canopy
+    /// looked up by name in the library
+    pub fn from_library(
+        library: &MaterialLibrary,
+        ground: &str,
+        walls: &str,
+        canopy: &str,
+    ) -> Result<Surfaces, Error> {
+        let material = |name: &str| {
+            library
+                .get(name)
+                .ok_or_else(|| Error::UnknownMaterial(name.to_string()))
+        };
+        let output = Surfaces {
+            ground: material(ground)?,
+            walls: material(walls)?,
+            canopy: material(canopy)?,
+        };
+        Ok(output)
+    }
+
+    /// Checks every material is valid
+    pub fn validate(&self) -> Result<(), Error> {
+        self.ground.validate()?;
+        self.walls.validate()?;
+        self.canopy.validate()
+    }
+}
+
+/// Named collection of materials
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct MaterialLibrary {
+    materials: BTreeMap<String, Material>,
+}
+
+impl Default for MaterialLibrary {
+    fn default() -> MaterialLibrary {
+        let materials = [
+            ("asphalt", Material::ASPHALT),
+            ("brick", Material::BRICK),
+            ("klinkers", Material::KLINKERS),
+            ("concrete", Material::CONCRETE),
+            ("grass", Material::GRASS),
+            ("water", Material::WATER),
+            ("tree_canopy", Material::TREE_CANOPY),
+            ("green_roof", Material::GREEN_ROOF),
+            ("cool_pavement", Material::COOL_PAVEMENT),
+        ];
+        MaterialLibrary {
+            materials: materials
+                .iter()
+                .map(|(name, material)| (name.to_string(), *material))
+                .collect(),
+        }
+    }
+}
+
+impl MaterialLibrary {
+    /// Library without any materials
+    pub fn empty() -> MaterialLibrary {
+        MaterialLibrary {
+            materials: BTreeMap::new(),
+        }
+    }
+
+    /// Parses a library from TOML, with a table
+    /// per material
+    pub fn from_toml(input: &str) -> Result<MaterialLibrary, Error> {
+        let library: MaterialLibrary = toml::from_str(input)
+            .map_err(|error| Error::MaterialFile(error.to_string()))?;
+        library.validate()?;
+        Ok(library)
+    }
+
+    /// Parses a library from JSON, with an
+    /// object per material
+    pub fn from_json(input: &str) -> Result<MaterialLibrary, Error> {
+        let library: MaterialLibrary = serde_json::from_str(input)
+            .map_err(|error| Error::MaterialFile(error.to_string()))?;
+        library.validate()?;
+        Ok(library)
+    }
+
+    /// Reads a library from a `.toml` or
+    /// `.json` file
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<MaterialLibrary, Error> {
+        let path = path.as_ref();
+        let input = fs::read_to_string(path)
+            .map_err(|error| Error::MaterialFile(error.to_string()))?;
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("toml") => MaterialLibrary::from_toml(&input),
+            Some("json") => MaterialLibrary::from_json(&input),
+            _ => Err(Error::MaterialFile(format!(
+                "{} is not a .toml or .json file",
+                path.display()
+            ))),
+        }
+    }
+
+    /// Looks up a material by name
+    pub fn get(&self, name: &str) -> Option<Material> {
+        self.materials.get(name).copied()
+    }
+
+    /// Adds or replaces a material
+    pub fn insert<S: Into<String>>(&mut self, name: S, material: Material) {
+        self.materials.insert(name.into(), material);
+    }
+
+    /// Adds or replaces every material of
+    /// the other library
+    pub fn extend(&mut self, other: MaterialLibrary) {
+        self.materials.extend(other.materials);
+    }
+
+    /// Names and materials in alphabetical order
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Material)> + '_ {
+        self.materials
+            .iter()
+            .map(|(name, material)| (name.as_str(), *material))
+    }
+
+    // Function to check every material is valid
+    fn validate(&self) -> Result<(), Error> {
+        self.materials
+            .values()
+            .try_for_each(|material| material.validate())
+    }
+}
@@ -0,0 +1,288 @@
+//! Module containing the Python bindings,
+//! built into a wheel with maturin
+
+use std::borrow::Cow;
+use std::convert::TryFrom;
+
+use chrono::{DateTime, TimeZone, Utc};
+use numpy::datetime::{units, Datetime};
+use numpy::{
+    Element, IntoPyArray, PyArray1, PyArrayDescrMethods, PyReadonlyArray1,
+    PyUntypedArray, PyUntypedArrayMethods,
+};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::error::Error;
+use crate::heat_indices::{
+    self, utci_approx_slice, Location, Observation, ObservationColumns,
+    StressCategory,
+};
+
+impl From<Error> for PyErr {
+    fn from(error: Error) -> PyErr {
+        PyValueError::new_err(error.to_string())
+    }
+}
+
+// Function to borrow the values of an array,
+// copying them when the array is strided
+fn values<'a, T: Element + Copy>(
+    array: &'a PyReadonlyArray1<'_, T>,
+) -> Cow<'a, [T]> {
+    match array.as_slice() {
+        Ok(slice) => Cow::Borrowed(slice),
+        Err(_) => Cow::Owned(array.as_array().to_vec()),
+    }
+}
+
+// Function to read an array of any integer type
+// as indices into the given number of locations
+fn location_indices(
+    location: &Bound<'_, PyUntypedArray>,
+    len: usize,
+) -> PyResult<Vec<usize>> {
+    if !matches!(location.dtype().kind(), b'i' | b'u') {
+        return Err(PyValueError::new_err(
+            "location must be an array of integers",
+        ));
+    }
+    let kwargs = PyDict::new(location.py());
+    kwargs.set_item("casting", "safe")?;
+    let location: PyReadonlyArray1<'_, i64> = location
+        .call_method("astype", ("int64",), Some(&kwargs))?
+        .extract()?;
+    location
+        .as_array()
+        .iter()
+        .map(|index| {
+            usize::try_from(*index)
+                .ok()
+                .filter(|index| *index < len)
+                .ok_or_else(|| {
+                    PyValueError::new_err(format!(
+                        "location {} is not an index into the {} locations",
+                        index, len,
+                    ))
+                })
+        })
+        .collect()
+}
+
+/// Experienced Heat Stress of an observation
+#[pyclass(name = "HeatStress", module = "utci", frozen)]
+#[derive(Debug, Clone, Copy)]
+struct PyHeatStress {
+    /// UTCI equivalent temperature [C]
+    #[pyo3(get)]
+    utci: f32,
+    /// Mean radiant temperature [C]
+    #[pyo3(get)]
+    radiant_temperature: f32,
+}
+
+#[pymethods]
+impl PyHeatStress {
+    /// English name of the stress category
+    #[getter]
+    fn category(&self) -> &'static str {
+        StressCategory::from_utci(self.utci).name()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "HeatStress(utci={:.1}, radiant_temperature={:.1})",
+            self.utci, self.radiant_temperature,
+        )
+    }
+}
+
+/// Location of a sensor in Groningen
+#[pyclass(name = "Location", module = "utci", frozen)]
+#[derive(Debug, Clone, Copy)]
+struct PyLocation(Location);
+
+#[pymethods]
+impl PyLocation {
+    #[new]
+    #[pyo3(signature = (binnenstad=false, park=false, shade=false))]
+    fn new(binnenstad: bool, park: bool, shade: bool) -> PyLocation {
+        PyLocation(Location::groningen(binnenstad, park, shade))
+    }
+}
+
+/// Calculates experienced Heat Stress in Groningen,
+/// at the current time unless a timezone aware
+/// timestamp is given
+#[pyfunction]
+#[pyo3(signature = (
+    air_temperature,
+    wind_speed,
+    relative_humidity,
+    binnenstad=false,
+    park=false,
+    shade=false,
+    timestamp=None,
+))]
+fn experienced_heat_stress(
+    air_temperature: f32,
+    wind_speed: f32,
+    relative_humidity: f32,
+    binnenstad: bool,
+    park: bool,
+    shade: bool,
+    timestamp: Option<DateTime<Utc>>,
+) -> PyResult<PyHeatStress> {
+    let heat_stress = heat_indices::experienced_heat_stress(Observation {
+        air_temperature,
+        wind_speed,
+        relative_humidity,
+        binnenstad,
+        park,
+        shade,
+        timestamp: timestamp.unwrap_or_else(Utc::now),
+    })?;
+    Ok(PyHeatStress {
+        utci: heat_stress.utci,
+        radiant_temperature: heat_stress.radiant_temperature,
+    })
+}
+
+/// Calculates the UTCI of arrays of observations,
+/// with timestamps as datetime64[s] and the index
+/// of every observation into the locations
+///
+/// Observations outside of the supported
+/// ranges are returned as NaN. Strided arrays
+/// are copied before evaluation. The location
+/// may have any integer type, and an index
+/// outside of the locations raises ValueError.
+#[pyfunction]
+#[pyo3(signature = (
+    air_temperature,
+    wind_speed,
+    relative_humidity,
+    timestamp,
+    locations,
+    location=None,
+))]
+fn experienced_heat_stress_batch<'py>(
+    py: Python<'py>,
+    air_temperature: PyReadonlyArray1<'py, f32>,
+    wind_speed: PyReadonlyArray1<'py, f32>,
+    relative_humidity: PyReadonlyArray1<'py, f32>,
+    timestamp: PyReadonlyArray1<'py, Datetime<units::Seconds>>,
+    locations: Vec<PyLocation>,
+    location: Option<Bound<'py, PyUntypedArray>>,
+) -> PyResult<Bound<'py, PyArray1<f32>>> {
+    let timestamp = timestamp
+        .as_array()
+        .iter()
+        .map(|seconds| {
+            Utc.timestamp_opt(i64::from(*seconds), 0)
+                .single()
+                .ok_or(Error::InvalidTimestamp)
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+    let locations: Vec<Location> =
+        locations.iter().map(|location| location.0).collect();
+    let location = match &location {
+        Some(location) => location_indices(location, locations.len())?,
+        None => Vec::new(),
+    };
+
+    let columns = ObservationColumns {
+        air_temperature: &values(&air_temperature),
+        wind_speed: &values(&wind_speed),
+        relative_humidity: &values(&relative_humidity),
+        timestamp: &timestamp,
+        location: &location,
+        global_radiation: &[],
+    };
+    let mut output = vec![0f32; columns.len()];
+    heat_indices::experienced_heat_stress_batch(
+        &columns,
+        &locations,
+        &mut output,
+    )?;
+    Ok(output.into_pyarray(py))
+}
+
+/// Evaluates the UTCI_approx polynomial over
+/// arrays of air temperature [C], wind speed [m/s],
+/// radiant temperature difference [C] and vapour
+/// pressure [kPa]
+#[pyfunction]
+fn utci_approx<'py>(
+    py: Python<'py>,
+    air_temperature: PyReadonlyArray1<'py, f64>,
+    wind_speed: PyReadonlyArray1<'py, f64>,
+    temp_diff: PyReadonlyArray1<'py, f64>,
+    vapour_pressure: PyReadonlyArray1<'py, f64>,
+) -> PyResult<Bound<'py, PyArray1<f64>>> {
+    let mut output = vec![0f64; air_temperature.len()];
+    utci_approx_slice(
+        &values(&air_temperature),
+        &values(&wind_speed),
+        &values(&temp_diff),
+        &values(&vapour_pressure),
+        &mut output,
+    )?;
+    Ok(output.into_pyarray(py))
+}
+
+/// Name of the stress category of a UTCI
+/// value, in Dutch when asked
+#[pyfunction]
+#[pyo3(signature = (utci, dutch=false))]
+fn stress_category(utci: f32, dutch: bool) -> &'static str {
+    let category = StressCategory::from_utci(utci);
+    if dutch {
+        category.dutch_name()
+    } else {
+        category.name()
+    }
+}
+
+/// Index into `STRESS_CATEGORIES` of the
+/// category of every UTCI value
+#[pyfunction]
+fn stress_category_index<'py>(
+    py: Python<'py>,
+    utci: PyReadonlyArray1<'py, f32>,
+) -> Bound<'py, PyArray1<u8>> {
+    let output: Vec<u8> = utci
+        .as_array()
+        .iter()
+        .map(|utci| StressCategory::from_utci(*utci) as u8)
+        .collect();
+    output.into_pyarray(py)
+}
+
+/// Python module exposing the heat stress model
+#[pymodule]
+fn utci(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyHeatStress>()?;
+    module.add_class::<PyLocation>()?;
+    module.add_function(wrap_pyfunction!(experienced_heat_stress, module)?)?;
+    module.add_function(wrap_pyfunction!(
+        experienced_heat_stress_batch,
+        module
+    )?)?;
+    module.add_function(wrap_pyfunction!(utci_approx, module)?)?;
+    module.add_function(wrap_pyfunction!(stress_category, module)?)?;
+    module.add_function(wrap_pyfunction!(stress_category_index, module)?)?;
+
+    let names: Vec<&str> = StressCategory::ALL
+        .iter()
+        .map(|category| category.name())
+        .collect();
+    let colours: Vec<&str> = StressCategory::ALL
+        .iter()
+        .map(|category| category.colour())
+        .collect();
+    module.add("STRESS_CATEGORIES", names)?;
+    module.add("STRESS_COLOURS", colours)?;
+    Ok(())
+}
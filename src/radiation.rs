@@ -0,0 +1,141 @@
+//! Module containing the mean radiant temperature
+//! of a standing person, integrated from the
+//! radiation received from six directions
+
+use crate::error::Error;
+
+/// Stefan-Boltzmann constant [W/m2/K4]
+pub const STEFAN_BOLTZMANN: f32 = 5.67e-8;
+
+/// Fraction of short wave radiation absorbed
+/// by the human body
+pub const SHORT_WAVE_ABSORPTION: f32 = 0.7;
+
+/// Emissivity, and fraction of long wave radiation
+/// absorbed, of the human body
+pub const HUMAN_EMISSIVITY: f32 = 0.97;
+
+/// Directions from which radiation reaches a person
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// Radiation from the sky and canopy above
+    Up,
+    /// Radiation from the ground below
+    Down,
+    /// Radiation from the north
+    North,
+    /// Radiation from the east
+    East,
+    /// Radiation from the south
+    South,
+    /// Radiation from the west
+    West,
+}
+
+impl Direction {
+    /// Every direction, in the order used to
+    /// index `DirectionalFluxes`
+    pub const ALL: [Direction; 6] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// Angular weighting factor of a standing
+    /// person, following Fanger (1970)
+    pub fn weighting_factor(self) -> f32 {
+        match self {
+            Direction::Up | Direction::Down => 0.06,
+            _ => 0.22,
+        }
+    }
+
+    /// Azimuth the radiation comes from, clockwise
+    /// from north [degrees], none for up and down
+    pub fn azimuth(self) -> Option<f32> {
+        match self {
+            Direction::Up | Direction::Down => None,
+            Direction::North => Some(0.0),
+            Direction::East => Some(90.0),
+            Direction::South => Some(180.0),
+            Direction::West => Some(270.0),
+        }
+    }
+
+    // Function to find the index within
+    // the flux arrays
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Short and long wave radiation received
+/// from every direction [W/m2]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct DirectionalFluxes {
+    /// Short wave radiation, indexed by `Direction`
+    pub short_wave: [f32; 6],
+    /// Long wave radiation, indexed by `Direction`
+    pub long_wave: [f32; 6],
+}
+
+impl DirectionalFluxes {
+    /// Short wave radiation from the direction
+    pub fn short_wave(&self, direction: Direction) -> f32 {
+        self.short_wave[direction.index()]
+    }
+
+    /// Long wave radiation from the direction
+    pub fn long_wave(&self, direction: Direction) -> f32 {
+        self.long_wave[direction.index()]
+    }
+
+    /// Sets the radiation from the direction
+    pub fn set(
+        &mut self,
+        direction: Direction,
+        short_wave: f32,
+        long_wave: f32,
+    ) {
+        self.short_wave[direction.index()] = short_wave;
+        self.long_wave[direction.index()] = long_wave;
+    }
+
+    /// Mean radiant flux density absorbed by
+    /// a standing person [W/m2]
+    pub fn absorbed(&self) -> f32 {
+        Direction::ALL.iter().fold(0.0, |sum, direction| {
+            sum + direction.weighting_factor()
+                * (SHORT_WAVE_ABSORPTION * self.short_wave(*direction)
+                    + HUMAN_EMISSIVITY * self.long_wave(*direction))
+        })
+    }
+}
+
+/// Method for calculating the Mean Radiant
+/// Temperature [C] from directional fluxes
+///
+/// Short wave fluxes must be within (0, 1400)
+/// and long wave fluxes within (0, 1000) W/m2.
+pub fn mean_radiant_temperature(
+    fluxes: &DirectionalFluxes,
+) -> Result<f32, Error> {
+    let valid_short_wave = fluxes
+        .short_wave
+        .iter()
+        .all(|flux| (0.0..=1400.0).contains(flux));
+    let valid_long_wave = fluxes
+        .long_wave
+        .iter()
+        .all(|flux| (0.0..=1000.0).contains(flux));
+    if !valid_short_wave || !valid_long_wave {
+        return Err(Error::InvalidRadiantFlux);
+    }
+
+    let aggregate = fluxes.absorbed() / (HUMAN_EMISSIVITY * STEFAN_BOLTZMANN);
+    let output = aggregate.powf(0.25) - 273.15;
+    Ok(output)
+}
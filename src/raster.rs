@@ -0,0 +1,183 @@
+//! Module containing rasters of values over
+//! the ground, such as land cover and surface
+//! heights of the city
+
+use crate::error::Error;
+
+/// Placement of a raster in a projected
+/// coordinate reference system
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeoTransform {
+    /// Easting of the upper left corner [m]
+    pub origin_x: f64,
+    /// Northing of the upper left corner [m]
+    pub origin_y: f64,
+    /// Width of a pixel from west to east [m]
+    pub pixel_width: f64,
+    /// Height of a pixel from north to south [m]
+    pub pixel_height: f64,
+}
+
+impl Default for GeoTransform {
+    fn default() -> GeoTransform {
+        GeoTransform {
+            origin_x: 0.0,
+            origin_y: 0.0,
+            pixel_width: 1.0,
+            pixel_height: 1.0,
+        }
+    }
+}
+
+impl GeoTransform {
+    /// Coordinates of the centre of a pixel
+    pub fn centre(&self, column: usize, row: usize) -> (f64, f64) {
+        let x = self.origin_x + (column as f64 + 0.5) * self.pixel_width;
+        let y = self.origin_y - (row as f64 + 0.5) * self.pixel_height;
+        (x, y)
+    }
+}
+
+/// Keys of the coordinate reference system of a
+/// GeoTIFF, carried over unchanged to rasters
+/// derived from it
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct GeoKeys {
+    /// GeoKeyDirectory entries
+    pub directory: Vec<u16>,
+    /// GeoDoubleParams values
+    pub doubles: Vec<f64>,
+    /// GeoAsciiParams text
+    pub ascii: String,
+}
+
+/// Values over a regular grid, stored row by
+/// row from the north west corner
+#[derive(Debug, Clone, PartialEq)]
+pub struct Raster<T> {
+    width: usize,
+    height: usize,
+    data: Vec<T>,
+    /// Placement of the raster
+    pub transform: GeoTransform,
+    /// Coordinate reference system of the raster
+    pub geo_keys: GeoKeys,
+}
+
+impl<T: Copy> Raster<T> {
+    /// Raster filled with a single value
+    pub fn filled(
+        width: usize,
+        height: usize,
+        value: T,
+        transform: GeoTransform,
+    ) -> Raster<T> {
+        Raster {
+            width,
+            height,
+            data: vec![value; width * height],
+            transform,
+            geo_keys: GeoKeys::default(),
+        }
+    }
+
+    /// Raster of the values, given row by row
+    ///
+    /// Values which do not fill the raster
+    /// are reported as an `Error`.
+    pub fn from_vec(
+        width: usize,
+        height: usize,
+        data: Vec<T>,
+        transform: GeoTransform,
+    ) -> Result<Raster<T>, Error> {
+        if data.len() != width * height {
+            return Err(Error::InvalidInput);
+        }
+        Ok(Raster {
+            width,
+            height,
+            data,
+            transform,
+            geo_keys: GeoKeys::default(),
+        })
+    }
+
+    /// Number of columns
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Number of rows
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Values of the raster, row by row
+    pub fn data(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Value of a pixel, or none outside
+    /// of the raster
+    pub fn get(&self, column: usize, row: usize) -> Option<T> {
+        if column < self.width && row < self.height {
+            Some(self.data[row * self.width + column])
+        } else {
+            None
+        }
+    }
+
+    /// Pixel containing the coordinates, or
+    /// none outside of the raster
+    pub fn pixel_at(&self, x: f64, y: f64) -> Option<(usize, usize)> {
+        let column = (x - self.transform.origin_x) / self.transform.pixel_width;
+        let row = (self.transform.origin_y - y) / self.transform.pixel_height;
+        if column >= 0.0
+            && row >= 0.0
+            && column < self.width as f64
+            && row < self.height as f64
+        {
+            Some((column as usize, row as usize))
+        } else {
+            None
+        }
+    }
+
+    /// Changes the value of a pixel within
+    /// the raster
+    pub fn set(&mut self, column: usize, row: usize, value: T) {
+        if column < self.width && row < self.height {
+            self.data[row * self.width + column] = value;
+        }
+    }
+
+    /// Raster of the same placement with every
+    /// value converted
+    pub fn map<U: Copy, F: Fn(T) -> U>(&self, convert: F) -> Raster<U> {
+        Raster {
+            width: self.width,
+            height: self.height,
+            data: self.data.iter().map(|value| convert(*value)).collect(),
+            transform: self.transform,
+            geo_keys: self.geo_keys.clone(),
+        }
+    }
+
+    /// Raster of the same placement holding
+    /// the given values
+    pub fn with_data<U: Copy>(&self, data: Vec<U>) -> Result<Raster<U>, Error> {
+        let mut raster =
+            Raster::from_vec(self.width, self.height, data, self.transform)?;
+        raster.geo_keys = self.geo_keys.clone();
+        Ok(raster)
+    }
+
+    /// Whether the other raster covers the same
+    /// pixels, so the two can be combined
+    pub fn is_aligned<U>(&self, other: &Raster<U>) -> bool {
+        self.width == other.width
+            && self.height == other.height
+            && self.transform == other.transform
+    }
+}
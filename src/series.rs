@@ -0,0 +1,402 @@
+//! Module containing the ingestion of weather
+//! station time series from CSV, annotated
+//! with experienced Heat Stress
+
+use std::io;
+
+use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+use crate::heat_indices::{experienced_heat_stress, Observation};
+
+/// Unit of a temperature column
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum TemperatureUnit {
+    /// Degrees Celsius
+    #[default]
+    Celsius,
+    /// Kelvin
+    Kelvin,
+    /// Degrees Fahrenheit
+    Fahrenheit,
+}
+
+impl TemperatureUnit {
+    /// Converts a value in the unit to Celsius
+    pub fn to_celsius(self, value: f32) -> f32 {
+        match self {
+            TemperatureUnit::Celsius => value,
+            TemperatureUnit::Kelvin => value - 273.15,
+            TemperatureUnit::Fahrenheit => (value - 32.0) * 5.0 / 9.0,
+        }
+    }
+}
+
+/// Unit of a wind speed column
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum WindSpeedUnit {
+    /// Metres per second
+    #[default]
+    MetresPerSecond,
+    /// Kilometres per hour
+    KilometresPerHour,
+    /// Knots
+    Knots,
+}
+
+impl WindSpeedUnit {
+    /// Converts a value in the unit to m/s
+    pub fn to_metres_per_second(self, value: f32) -> f32 {
+        match self {
+            WindSpeedUnit::MetresPerSecond => value,
+            WindSpeedUnit::KilometresPerHour => value / 3.6,
+            WindSpeedUnit::Knots => value * 0.514_444,
+        }
+    }
+}
+
+/// Unit of a relative humidity column
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum HumidityUnit {
+    /// Percentage (0, 100)
+    #[default]
+    Percent,
+    /// Fraction (0, 1)
+    Fraction,
+}
+
+impl HumidityUnit {
+    /// Converts a value in the unit to a percentage
+    pub fn to_percent(self, value: f32) -> f32 {
+        match self {
+            HumidityUnit::Percent => value,
+            HumidityUnit::Fraction => value * 100.0,
+        }
+    }
+}
+
+/// Names and units of the columns of a station
+/// export, with the location of the station
+///
+/// Without a `timestamp_format` timestamps are
+/// read as RFC 3339, otherwise with the chrono
+/// format, as UTC unless it holds an offset.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ColumnMapping {
+    /// Column holding the moment of the observation
+    pub timestamp: String,
+    /// Column holding the air temperature
+    pub air_temperature: String,
+    /// Column holding the wind speed
+    pub wind_speed: String,
+    /// Column holding the relative humidity
+    pub relative_humidity: String,
+    /// Format of the timestamps
+    pub timestamp_format: Option<String>,
+    /// Unit of the air temperature
+    pub temperature_unit: TemperatureUnit,
+    /// Unit of the wind speed
+    pub wind_speed_unit: WindSpeedUnit,
+    /// Unit of the relative humidity
+    pub humidity_unit: HumidityUnit,
+    /// ASCII field delimiter, other characters
+    /// are refused with `Error::CsvFile`
+    pub delimiter: char,
+    /// Station situated in the city centre
+    pub binnenstad: bool,
+    /// Station situated in a park
+    pub park: bool,
+    /// Station situated under the shade of a tree
+    pub shade: bool,
+}
+
+impl Default for ColumnMapping {
+    fn default() -> ColumnMapping {
+        ColumnMapping {
+            timestamp: String::from("timestamp"),
+            air_temperature: String::from("air_temperature"),
+            wind_speed: String::from("wind_speed"),
+            relative_humidity: String::from("relative_humidity"),
+            timestamp_format: None,
+            temperature_unit: TemperatureUnit::Celsius,
+            wind_speed_unit: WindSpeedUnit::MetresPerSecond,
+            humidity_unit: HumidityUnit::Percent,
+            delimiter: ',',
+            binnenstad: false,
+            park: false,
+            shade: false,
+        }
+    }
+}
+
+impl ColumnMapping {
+    /// Parses a mapping from TOML, taking the
+    /// defaults for missing keys
+    pub fn from_toml(input: &str) -> Result<ColumnMapping, Error> {
+        let mapping: ColumnMapping = toml::from_str(input)
+            .map_err(|error| Error::CsvFile(error.to_string()))?;
+        mapping.delimiter_byte()?;
+        Ok(mapping)
+    }
+
+    // Function to convert the delimiter into the
+    // single byte the CSV reader splits on
+    fn delimiter_byte(&self) -> Result<u8, Error> {
+        if self.delimiter.is_ascii() {
+            Ok(self.delimiter as u8)
+        } else {
+            Err(Error::CsvFile(format!(
+                "Delimiter {:?} is not an ASCII character",
+                self.delimiter
+            )))
+        }
+    }
+
+    // Function to find the position of every
+    // mapped column within the header
+    fn locate(&self, header: &csv::StringRecord) -> Result<Columns, Error> {
+        let find = |name: &str| {
+            header
+                .iter()
+                .position(|column| column.trim() == name)
+                .ok_or_else(|| Error::MissingColumn(name.to_string()))
+        };
+        Ok(Columns {
+            timestamp: find(&self.timestamp)?,
+            air_temperature: find(&self.air_temperature)?,
+            wind_speed: find(&self.wind_speed)?,
+            relative_humidity: find(&self.relative_humidity)?,
+        })
+    }
+
+    // Function to read the observation of a row
+    fn observation(
+        &self,
+        columns: &Columns,
+        record: &csv::StringRecord,
+    ) -> Result<Observation, Error> {
+        let timestamp = record
+            .get(columns.timestamp)
+            .ok_or(Error::InvalidTimestamp)
+            .and_then(|field| self.parse_timestamp(field.trim()))?;
+        let air_temperature =
+            number(record, columns.air_temperature, &self.air_temperature)?;
+        let wind_speed = number(record, columns.wind_speed, &self.wind_speed)?;
+        let relative_humidity =
+            number(record, columns.relative_humidity, &self.relative_humidity)?;
+
+        Ok(Observation {
+            air_temperature: self.temperature_unit.to_celsius(air_temperature),
+            wind_speed: self.wind_speed_unit.to_metres_per_second(wind_speed),
+            relative_humidity: self.humidity_unit.to_percent(relative_humidity),
+            binnenstad: self.binnenstad,
+            park: self.park,
+            shade: self.shade,
+            timestamp,
+        })
+    }
+
+    // Function to read a timestamp in the
+    // configured format
+    fn parse_timestamp(&self, field: &str) -> Result<DateTime<Utc>, Error> {
+        let timestamp = match &self.timestamp_format {
+            None => DateTime::parse_from_rfc3339(field)
+                .map(|timestamp| timestamp.with_timezone(&Utc)),
+            Some(format) => DateTime::parse_from_str(field, format)
+                .map(|timestamp| timestamp.with_timezone(&Utc))
+                .or_else(|_| {
+                    NaiveDateTime::parse_from_str(field, format)
+                        .map(|timestamp| Utc.from_utc_datetime(&timestamp))
+                }),
+        };
+        timestamp.map_err(|_| Error::InvalidTimestamp)
+    }
+
+    // Function to build a CSV reader for the mapping
+    fn reader<R: io::Read>(&self, input: R) -> Result<csv::Reader<R>, Error> {
+        Ok(csv::ReaderBuilder::new()
+            .delimiter(self.delimiter_byte()?)
+            .flexible(true)
+            .from_reader(input))
+    }
+}
+
+// Function to read the number in a field,
+// naming the column when it is empty
+fn number(
+    record: &csv::StringRecord,
+    index: usize,
+    name: &str,
+) -> Result<f32, Error> {
+    match record.get(index).map(str::trim) {
+        None | Some("") => Err(Error::MissingValue(name.to_string())),
+        Some(field) => field.parse().map_err(|_| Error::InvalidInput),
+    }
+}
+
+// Function to report a record the CSV reader
+// could not read as the failure of its row,
+// leaving an unreadable input an `Error`
+fn record_error(error: csv::Error) -> Result<RowError, Error> {
+    if error.is_io_error() {
+        return Err(Error::CsvFile(error.to_string()));
+    }
+    Ok(RowError {
+        line: error.position().map_or(0, |position| position.line()),
+        error: Error::CsvFile(error.to_string()),
+    })
+}
+
+// Positions of the mapped columns
+struct Columns {
+    timestamp: usize,
+    air_temperature: usize,
+    wind_speed: usize,
+    relative_humidity: usize,
+}
+
+/// Row of a data file which could not be
+/// turned into experienced Heat Stress
+#[derive(Debug, Clone, PartialEq)]
+pub struct RowError {
+    /// Line of the row within the file,
+    /// counting from 1
+    pub line: u64,
+    /// Reason the row failed
+    pub error: Error,
+}
+
+/// Outcome of annotating a CSV file
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AnnotationSummary {
+    /// Number of rows read
+    pub rows: usize,
+    /// Rows which failed, in order
+    pub failures: Vec<RowError>,
+}
+
+/// Method for reading the observations of a
+/// CSV station export
+///
+/// A missing column or unreadable file is
+/// reported as an `Error`, a row which cannot
+/// be read as a `RowError` in its place.
+pub fn read_observations<R: io::Read>(
+    input: R,
+    mapping: &ColumnMapping,
+) -> Result<Vec<Result<Observation, RowError>>, Error> {
+    let mut reader = mapping.reader(input)?;
+    let header = reader
+        .headers()
+        .map_err(|error| Error::CsvFile(error.to_string()))?
+        .clone();
+    let columns = mapping.locate(&header)?;
+
+    let mut observations = Vec::new();
+    for record in reader.records() {
+        let record = match record {
+            Ok(record) => record,
+            Err(error) => {
+                observations.push(Err(record_error(error)?));
+                continue;
+            }
+        };
+        let line = record.position().map_or(0, |position| position.line());
+        observations.push(
+            mapping
+                .observation(&columns, &record)
+                .map_err(|error| RowError { line, error }),
+        );
+    }
+    Ok(observations)
+}
+
+/// Method for annotating a CSV station export
+/// with the UTCI, mean radiant temperature and
+/// stress category of every row
+///
+/// Every column of the input is kept. Rows which
+/// fail are written with empty results and the
+/// reason in an `error` column, and returned in
+/// the summary rather than aborting.
+pub fn annotate_csv<R: io::Read, W: io::Write>(
+    input: R,
+    output: W,
+    mapping: &ColumnMapping,
+) -> Result<AnnotationSummary, Error> {
+    let csv_error = |error: csv::Error| Error::CsvFile(error.to_string());
+    let mut reader = mapping.reader(input)?;
+    let mut writer = csv::WriterBuilder::new()
+        .delimiter(mapping.delimiter_byte()?)
+        .flexible(true)
+        .from_writer(output);
+
+    let mut header = reader.headers().map_err(csv_error)?.clone();
+    let columns = mapping.locate(&header)?;
+    let width = header.len();
+    header.push_field("utci");
+    header.push_field("radiant_temperature");
+    header.push_field("category");
+    header.push_field("error");
+    writer.write_record(&header).map_err(csv_error)?;
+
+    let mut summary = AnnotationSummary::default();
+    for record in reader.byte_records() {
+        summary.rows += 1;
+        let mut record = match record {
+            Ok(record) => record,
+            Err(error) => {
+                summary.failures.push(record_error(error)?);
+                continue;
+            }
+        };
+        let line = record.position().map_or(0, |position| position.line());
+
+        // keep the results aligned for short rows
+        while record.len() < width {
+            record.push_field(b"");
+        }
+
+        // rows which are not valid UTF-8 are
+        // written back as they were read
+        let result = csv::StringRecord::from_byte_record(record.clone())
+            .map_err(|error| Error::CsvFile(error.to_string()))
+            .and_then(|record| mapping.observation(&columns, &record))
+            .and_then(experienced_heat_stress);
+        match result {
+            Ok(heat_stress) => {
+                record
+                    .push_field(format!("{:.2}", heat_stress.utci).as_bytes());
+                record.push_field(
+                    format!("{:.2}", heat_stress.radiant_temperature)
+                        .as_bytes(),
+                );
+                record.push_field(heat_stress.category().name().as_bytes());
+                record.push_field(b"");
+            }
+            Err(error) => {
+                record.push_field(b"");
+                record.push_field(b"");
+                record.push_field(b"");
+                record.push_field(error.to_string().as_bytes());
+                summary.failures.push(RowError { line, error });
+            }
+        }
+        writer.write_record(&record).map_err(csv_error)?;
+    }
+    writer
+        .flush()
+        .map_err(|error| Error::CsvFile(error.to_string()))?;
+    Ok(summary)
+}
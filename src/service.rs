@@ -0,0 +1,93 @@
+//! Module containing the JSON shapes shared by the
+//! HTTP service and the WebAssembly bindings
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::heat_indices::{HeatStress, Observation, StressCategory};
+
+/// Observation as sent by a client, with the
+/// location flags defaulting to false
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct ObservationInput {
+    /// Air temperature [C]
+    pub air_temperature: f32,
+    /// Wind speed [m/s]
+    pub wind_speed: f32,
+    /// Relative humidity [%]
+    pub relative_humidity: f32,
+    /// Situated in the city centre
+    #[serde(default)]
+    pub binnenstad: bool,
+    /// Situated in a park
+    #[serde(default)]
+    pub park: bool,
+    /// Situated under the shade of a tree
+    #[serde(default)]
+    pub shade: bool,
+    /// Moment of the observation as ISO 8601
+    #[serde(default)]
+    pub timestamp: Option<DateTime<Utc>>,
+}
+
+impl ObservationInput {
+    /// Observation at the given moment unless
+    /// the input has a timestamp
+    pub fn observation(self, now: DateTime<Utc>) -> Observation {
+        Observation {
+            air_temperature: self.air_temperature,
+            wind_speed: self.wind_speed,
+            relative_humidity: self.relative_humidity,
+            binnenstad: self.binnenstad,
+            park: self.park,
+            shade: self.shade,
+            timestamp: self.timestamp.unwrap_or(now),
+        }
+    }
+}
+
+/// Category of the UTCI scale with its
+/// names and colour
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct CategoryOutput {
+    /// Category of the UTCI assessment scale
+    pub category: StressCategory,
+    /// English name
+    pub name: &'static str,
+    /// Dutch name
+    pub dutch_name: &'static str,
+    /// Colour as a hex code
+    pub colour: &'static str,
+}
+
+impl From<StressCategory> for CategoryOutput {
+    fn from(category: StressCategory) -> CategoryOutput {
+        CategoryOutput {
+            category,
+            name: category.name(),
+            dutch_name: category.dutch_name(),
+            colour: category.colour(),
+        }
+    }
+}
+
+/// Experienced Heat Stress with its category,
+/// serialized as a single flat object
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct HeatStressOutput {
+    /// UTCI and mean radiant temperature
+    #[serde(flatten)]
+    pub heat_stress: HeatStress,
+    /// Category of the UTCI
+    #[serde(flatten)]
+    pub category: CategoryOutput,
+}
+
+impl From<HeatStress> for HeatStressOutput {
+    fn from(heat_stress: HeatStress) -> HeatStressOutput {
+        HeatStressOutput {
+            heat_stress,
+            category: heat_stress.category().into(),
+        }
+    }
+}
@@ -0,0 +1,172 @@
+//! Module containing the shadows cast by the
+//! buildings and trees of a surface model
+
+use chrono::{DateTime, Duration, Utc};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+use crate::raster::Raster;
+use crate::solar::SolarPosition;
+
+/// Shading of a point, with the code written
+/// to shadow masks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[repr(u8)]
+pub enum Shading {
+    /// Reached by the sun
+    Sunlit = 0,
+    /// In the shadow of a building
+    Building = 1,
+    /// In the shadow of a tree, or under
+    /// its canopy
+    Tree = 2,
+    /// Sun below the horizon
+    Night = 3,
+}
+
+impl Shading {
+    /// Whether the point is shaded by a tree,
+    /// as the `shade` flag of an observation
+    pub fn is_tree_shade(self) -> bool {
+        self == Shading::Tree
+    }
+}
+
+/// Shadows over a raster at a moment
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShadowMask {
+    /// Moment of the position of the sun
+    pub timestamp: DateTime<Utc>,
+    /// Code of the `Shading` of every pixel
+    pub shading: Raster<u8>,
+}
+
+/// Method for finding the shadow over a pixel
+/// from the heights above the ground of the
+/// buildings and of the trees [m]
+///
+/// A ray is followed from the ground at the
+/// centre of the pixel towards the sun, up to
+/// the search distance [m]. The shadow of a
+/// building hides that of a tree, and the
+/// canopy is taken as reaching the ground.
+pub fn shadow_at(
+    buildings: &Raster<f32>,
+    vegetation: &Raster<f32>,
+    column: usize,
+    row: usize,
+    position: &SolarPosition,
+    search_distance: f32,
+) -> Shading {
+    if !position.is_daytime() {
+        return Shading::Night;
+    }
+    let mut shading = if vegetation.get(column, row).unwrap_or(0.0) > 0.0 {
+        Shading::Tree
+    } else {
+        Shading::Sunlit
+    };
+
+    let pixel_size = buildings.transform.pixel_width.abs() as f32;
+    let steps = (search_distance / pixel_size).ceil() as usize;
+    let (width, height) = (buildings.width() as f32, buildings.height() as f32);
+    let azimuth = position.azimuth.to_radians() as f32;
+    // rows run from north to south
+    let (dx, dy) = (azimuth.sin(), -azimuth.cos());
+    let slope = position.elevation().to_radians().tan() as f32;
+
+    for step in 1..=steps {
+        let x = column as f32 + 0.5 + dx * step as f32;
+        let y = row as f32 + 0.5 + dy * step as f32;
+        if x < 0.0 || y < 0.0 || x >= width || y >= height {
+            break;
+        }
+        let (x, y) = (x as usize, y as usize);
+        // the obstruction starts half a step
+        // before the point sampled
+        let ray_height = (step as f32 - 0.5) * pixel_size * slope;
+        if buildings.get(x, y).unwrap_or(0.0) > ray_height {
+            return Shading::Building;
+        }
+        if vegetation.get(x, y).unwrap_or(0.0) > ray_height {
+            shading = Shading::Tree;
+        }
+    }
+    shading
+}
+
+/// Method for finding the shadow over every
+/// pixel at a position of the sun, as a mask
+/// of `Shading` codes
+///
+/// Rasters which are not aligned, without
+/// square pixels, or a negative search
+/// distance are reported as an `Error`.
+pub fn shadow_mask(
+    buildings: &Raster<f32>,
+    vegetation: &Raster<f32>,
+    position: &SolarPosition,
+    search_distance: f32,
+) -> Result<Raster<u8>, Error> {
+    let transform = buildings.transform;
+    let pixel_size = transform.pixel_width.abs();
+    if !buildings.is_aligned(vegetation)
+        || !pixel_size.is_finite()
+        || pixel_size <= 0.0
+        || transform.pixel_width != transform.pixel_height
+        || !search_distance.is_finite()
+        || search_distance < 0.0
+    {
+        return Err(Error::InvalidInput);
+    }
+
+    let width = buildings.width();
+    let len = buildings.data().len();
+    let shade = |index: usize| {
+        let (column, row) = (index % width, index / width);
+        shadow_at(
+            buildings,
+            vegetation,
+            column,
+            row,
+            position,
+            search_distance,
+        ) as u8
+    };
+    #[cfg(feature = "rayon")]
+    let data: Vec<u8> = (0..len).into_par_iter().map(shade).collect();
+    #[cfg(not(feature = "rayon"))]
+    let data: Vec<u8> = (0..len).map(shade).collect();
+    buildings.with_data(data)
+}
+
+/// Method for finding the shadow masks of
+/// every hour from the start, at the latitude
+/// and longitude of the rasters [degrees]
+pub fn hourly_shadow_masks(
+    buildings: &Raster<f32>,
+    vegetation: &Raster<f32>,
+    start: DateTime<Utc>,
+    hours: usize,
+    latitude: f64,
+    longitude: f64,
+    search_distance: f32,
+) -> Result<Vec<ShadowMask>, Error> {
+    if !(-90.0..=90.0).contains(&latitude)
+        || !(-180.0..=180.0).contains(&longitude)
+    {
+        return Err(Error::InvalidCoordinates);
+    }
+    let mut output = Vec::with_capacity(hours);
+    for hour in 0..hours {
+        let timestamp = start + Duration::hours(hour as i64);
+        let position = SolarPosition::at(timestamp, latitude, longitude);
+        let shading =
+            shadow_mask(buildings, vegetation, &position, search_distance)?;
+        output.push(ShadowMask { timestamp, shading });
+    }
+    Ok(output)
+}
@@ -0,0 +1,54 @@
+//! Module containing the description of the
+//! site at which an observation is made
+
+use crate::canyon::CanyonGeometry;
+use crate::error::Error;
+use crate::materials::Surfaces;
+
+/// Surroundings of an observation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Site {
+    /// Geometry of the street canyon
+    pub canyon: CanyonGeometry,
+    /// Materials of the surrounding surfaces
+    pub surfaces: Surfaces,
+    /// Latitude, positive to the north [degrees]
+    pub latitude: f64,
+    /// Longitude, positive to the east [degrees]
+    pub longitude: f64,
+}
+
+impl Site {
+    /// Latitude of the centre of Groningen
+    pub const GRONINGEN_LATITUDE: f64 = 53.2194;
+
+    /// Longitude of the centre of Groningen
+    pub const GRONINGEN_LONGITUDE: f64 = 6.5665;
+
+    /// Preset of Groningen for the given
+    /// location flags
+    ///
+    /// A park within the city centre is
+    /// modelled as a park.
+    pub fn groningen(binnenstad: bool, park: bool) -> Site {
+        Site {
+            canyon: CanyonGeometry::groningen(binnenstad && !park),
+            surfaces: Surfaces::groningen(park),
+            latitude: Site::GRONINGEN_LATITUDE,
+            longitude: Site::GRONINGEN_LONGITUDE,
+        }
+    }
+
+    /// Checks the geometry, materials
+    /// and coordinates
+    pub fn validate(&self) -> Result<(), Error> {
+        self.canyon.validate()?;
+        self.surfaces.validate()?;
+        if !(-90.0..=90.0).contains(&self.latitude)
+            || !(-180.0..=180.0).contains(&self.longitude)
+        {
+            return Err(Error::InvalidCoordinates);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,212 @@
+//! Module containing the sky view factor of
+//! points in the city, scanned over the horizon
+//! of a raster of surface heights
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+use crate::raster::Raster;
+
+/// Settings of the scan over the horizon
+/// of a point
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HorizonScan {
+    /// Number of azimuth directions, evenly
+    /// spread clockwise from north
+    pub directions: usize,
+    /// Distance searched for obstructions [m]
+    pub search_distance: f32,
+}
+
+impl Default for HorizonScan {
+    fn default() -> HorizonScan {
+        HorizonScan {
+            directions: 36,
+            search_distance: 100.0,
+        }
+    }
+}
+
+impl HorizonScan {
+    /// Checks the number of directions
+    /// and the search distance
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.directions == 0
+            || !self.search_distance.is_finite()
+            || self.search_distance < 0.0
+        {
+            return Err(Error::InvalidInput);
+        }
+        Ok(())
+    }
+}
+
+/// Sky view factors of a point, seen past the
+/// buildings and past the trees
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SkyViewFactor {
+    /// Sky View Factor of the buildings (0, 1)
+    pub buildings: f32,
+    /// Sky View Factor of the trees (0, 1)
+    pub vegetation: f32,
+}
+
+impl SkyViewFactor {
+    /// Sky View Factor of the buildings and
+    /// trees together, with the given fraction
+    /// of the sky seen through the canopy
+    pub fn combined(&self, transmissivity: f32) -> f32 {
+        let hidden = (1.0 - self.vegetation) * (1.0 - transmissivity);
+        (self.buildings - hidden).max(0.0)
+    }
+}
+
+/// Method for calculating the Sky View Factor
+/// of a pixel from the heights above the
+/// ground of its surroundings [m]
+///
+/// The highest elevation angle of the horizon
+/// is found in every direction of the scan,
+/// and the sky seen by the horizontal ground
+/// at the centre of the pixel follows as the
+/// mean of their squared cosines. The pixel
+/// itself is no obstruction, and pixels
+/// outside of the raster or without a height
+/// (NaN) are open.
+/// Rasters without square pixels, or an
+/// invalid scan, are reported as an `Error`.
+pub fn sky_view_factor(
+    heights: &Raster<f32>,
+    column: usize,
+    row: usize,
+    scan: &HorizonScan,
+) -> Result<f32, Error> {
+    validate_scan(heights, scan)?;
+    Ok(scan_sky_view_factor(heights, column, row, scan))
+}
+
+/// Method for calculating the Sky View Factors
+/// of a pixel from the heights of the buildings
+/// and of the trees above the ground [m]
+///
+/// Rasters without square pixels, or an
+/// invalid scan, are reported as an `Error`.
+pub fn sky_view_factors(
+    buildings: &Raster<f32>,
+    vegetation: &Raster<f32>,
+    column: usize,
+    row: usize,
+    scan: &HorizonScan,
+) -> Result<SkyViewFactor, Error> {
+    validate_scan(buildings, scan)?;
+    validate_scan(vegetation, scan)?;
+    Ok(scan_sky_view_factors(
+        buildings, vegetation, column, row, scan,
+    ))
+}
+
+// Function to check the scan and the square
+// pixels of a height raster
+pub(crate) fn validate_scan(
+    heights: &Raster<f32>,
+    scan: &HorizonScan,
+) -> Result<(), Error> {
+    scan.validate()?;
+    let transform = heights.transform;
+    let pixel_size = transform.pixel_width.abs();
+    if !pixel_size.is_finite()
+        || pixel_size <= 0.0
+        || transform.pixel_width != transform.pixel_height
+    {
+        return Err(Error::InvalidInput);
+    }
+    Ok(())
+}
+
+// Function to scan the Sky View Factor of a
+// pixel of a validated raster
+fn scan_sky_view_factor(
+    heights: &Raster<f32>,
+    column: usize,
+    row: usize,
+    scan: &HorizonScan,
+) -> f32 {
+    let pixel_size = heights.transform.pixel_width.abs() as f32;
+    let steps = (scan.search_distance / pixel_size).ceil() as usize;
+    let (width, height) = (heights.width() as f32, heights.height() as f32);
+
+    let mut visible = 0.0;
+    for direction in 0..scan.directions {
+        let azimuth =
+            (360.0 * direction as f32 / scan.directions as f32).to_radians();
+        // rows run from north to south
+        let (dx, dy) = (azimuth.sin(), -azimuth.cos());
+
+        let mut max_slope = 0f32;
+        for step in 1..=steps {
+            let x = column as f32 + 0.5 + dx * step as f32;
+            let y = row as f32 + 0.5 + dy * step as f32;
+            if x < 0.0 || y < 0.0 || x >= width || y >= height {
+                break;
+            }
+            let obstruction =
+                heights.get(x as usize, y as usize).unwrap_or(0.0);
+            // the obstruction starts half a step
+            // before the point sampled
+            let distance = (step as f32 - 0.5) * pixel_size;
+            if obstruction / distance > max_slope {
+                max_slope = obstruction / distance;
+            }
+        }
+        // squared cosine of the horizon angle
+        visible += 1.0 / (1.0 + max_slope * max_slope);
+    }
+    visible / scan.directions as f32
+}
+
+// Function to scan the Sky View Factors of a
+// pixel of validated rasters
+pub(crate) fn scan_sky_view_factors(
+    buildings: &Raster<f32>,
+    vegetation: &Raster<f32>,
+    column: usize,
+    row: usize,
+    scan: &HorizonScan,
+) -> SkyViewFactor {
+    SkyViewFactor {
+        buildings: scan_sky_view_factor(buildings, column, row, scan),
+        vegetation: scan_sky_view_factor(vegetation, column, row, scan),
+    }
+}
+
+/// Method for calculating the Sky View Factor
+/// of every pixel of a height raster [m]
+///
+/// Rasters without square pixels, or an
+/// invalid scan, are reported as an `Error`.
+pub fn sky_view_factor_raster(
+    heights: &Raster<f32>,
+    scan: &HorizonScan,
+) -> Result<Raster<f32>, Error> {
+    validate_scan(heights, scan)?;
+
+    let width = heights.width();
+    let len = heights.data().len();
+    #[cfg(feature = "rayon")]
+    let data: Vec<f32> = (0..len)
+        .into_par_iter()
+        .map(|index| {
+            scan_sky_view_factor(heights, index % width, index / width, scan)
+        })
+        .collect();
+    #[cfg(not(feature = "rayon"))]
+    let data: Vec<f32> = (0..len)
+        .map(|index| {
+            scan_sky_view_factor(heights, index % width, index / width, scan)
+        })
+        .collect();
+    heights.with_data(data)
+}
@@ -0,0 +1,193 @@
+//! Module containing the position of the sun
+//! and the short wave radiation it delivers
+
+use std::f64::consts::PI;
+
+use chrono::{DateTime, Datelike, Timelike, Utc};
+
+/// Solar constant [W/m2]
+pub const SOLAR_CONSTANT: f64 = 1361.0;
+
+/// Position of the sun as seen from a
+/// location on the ground
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SolarPosition {
+    /// Declination of the sun [degrees]
+    pub declination: f64,
+    /// Hour angle, negative before solar noon [degrees]
+    pub hour_angle: f64,
+    /// Angle between the sun and the zenith [degrees]
+    pub zenith: f64,
+    /// Azimuth clockwise from north [degrees]
+    pub azimuth: f64,
+    /// Radiation at the top of the atmosphere [W/m2]
+    pub extraterrestrial: f64,
+}
+
+impl SolarPosition {
+    /// Position of the sun at the timestamp for
+    /// the latitude and longitude in degrees,
+    /// following the NOAA solar calculations
+    pub fn at(
+        timestamp: DateTime<Utc>,
+        latitude: f64,
+        longitude: f64,
+    ) -> SolarPosition {
+        let hours = f64::from(timestamp.hour())
+            + f64::from(timestamp.minute()) / 60.0
+            + f64::from(timestamp.second()) / 3600.0;
+        let days_in_year = if is_leap_year(timestamp.year()) {
+            366.0
+        } else {
+            365.0
+        };
+        // fractional year [radians]
+        let gamma = 2.0 * PI / days_in_year
+            * (f64::from(timestamp.ordinal()) - 1.0 + (hours - 12.0) / 24.0);
+
+        let declination = 0.006918 - 0.399912 * gamma.cos()
+            + 0.070257 * gamma.sin()
+            - 0.006758 * (2.0 * gamma).cos()
+            + 0.000907 * (2.0 * gamma).sin()
+            - 0.002697 * (3.0 * gamma).cos()
+            + 0.00148 * (3.0 * gamma).sin();
+
+        // equation of time [minutes]
+        let equation_of_time = 229.18
+            * (0.000075 + 0.001868 * gamma.cos()
+                - 0.032077 * gamma.sin()
+                - 0.014615 * (2.0 * gamma).cos()
+                - 0.040849 * (2.0 * gamma).sin());
+
+        let solar_time = hours * 60.0 + equation_of_time + 4.0 * longitude;
+        let hour_angle = (solar_time / 4.0 - 180.0).to_radians();
+
+        let latitude = latitude.to_radians();
+        let cos_zenith = (latitude.sin() * declination.sin()
+            + latitude.cos() * declination.cos() * hour_angle.cos())
+        .clamp(-1.0, 1.0);
+        let azimuth = hour_angle.sin().atan2(
+            hour_angle.cos() * latitude.sin()
+                - declination.tan() * latitude.cos(),
+        ) + PI;
+
+        let extraterrestrial = SOLAR_CONSTANT
+            * (1.00011
+                + 0.034221 * gamma.cos()
+                + 0.00128 * gamma.sin()
+                + 0.000719 * (2.0 * gamma).cos()
+                + 0.000077 * (2.0 * gamma).sin());
+
+        SolarPosition {
+            declination: declination.to_degrees(),
+            hour_angle: hour_angle.to_degrees(),
+            zenith: cos_zenith.acos().to_degrees(),
+            azimuth: azimuth.to_degrees(),
+            extraterrestrial,
+        }
+    }
+
+    /// Angle of the sun above the horizon [degrees]
+    pub fn elevation(&self) -> f64 {
+        90.0 - self.zenith
+    }
+
+    /// Whether the sun is above the horizon
+    pub fn is_daytime(&self) -> bool {
+        self.zenith < 90.0
+    }
+
+    /// Cosine of the zenith angle, zero at night
+    pub fn cos_zenith(&self) -> f64 {
+        self.zenith.to_radians().cos().max(0.0)
+    }
+}
+
+/// Short wave radiation split into its direct
+/// and diffuse components
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SolarRadiation {
+    /// Direct radiation on a surface normal
+    /// to the sun [W/m2]
+    pub direct_normal: f64,
+    /// Diffuse radiation on a horizontal
+    /// surface [W/m2]
+    pub diffuse_horizontal: f64,
+    /// Cosine of the solar zenith angle
+    pub cos_zenith: f64,
+}
+
+impl SolarRadiation {
+    /// Radiation when the sun is below the horizon
+    pub fn night() -> SolarRadiation {
+        SolarRadiation {
+            direct_normal: 0.0,
+            diffuse_horizontal: 0.0,
+            cos_zenith: 0.0,
+        }
+    }
+
+    /// Cloudless sky radiation following
+    /// Haurwitz (1945)
+    pub fn clear_sky(position: &SolarPosition) -> SolarRadiation {
+        let cos_zenith = position.cos_zenith();
+        if cos_zenith <= 0.0 {
+            return SolarRadiation::night();
+        }
+        let global = 1098.0 * cos_zenith * (-0.057 / cos_zenith).exp();
+        SolarRadiation::from_global_horizontal(global, position)
+    }
+
+    /// Splits a measured global horizontal
+    /// radiation [W/m2] into direct and diffuse
+    /// components following Erbs et al. (1982)
+    ///
+    /// The direct beam is limited to the radiation
+    /// at the top of the atmosphere, as near the
+    /// horizon a small global radiation would
+    /// otherwise give an unphysical beam.
+    pub fn from_global_horizontal(
+        global: f64,
+        position: &SolarPosition,
+    ) -> SolarRadiation {
+        let cos_zenith = position.cos_zenith();
+        if cos_zenith <= 0.0 || global <= 0.0 {
+            return SolarRadiation::night();
+        }
+        let clearness =
+            (global / (position.extraterrestrial * cos_zenith)).min(1.0);
+
+        let diffuse_fraction = if clearness <= 0.22 {
+            1.0 - 0.09 * clearness
+        } else if clearness <= 0.8 {
+            0.9511 - 0.1604 * clearness + 4.388 * clearness.powi(2)
+                - 16.638 * clearness.powi(3)
+                + 12.336 * clearness.powi(4)
+        } else {
+            0.165
+        };
+
+        let direct_normal = (global * (1.0 - diffuse_fraction) / cos_zenith)
+            .min(position.extraterrestrial);
+        SolarRadiation {
+            direct_normal,
+            diffuse_horizontal: global - direct_normal * cos_zenith,
+            cos_zenith,
+        }
+    }
+
+    /// Direct radiation on a horizontal surface [W/m2]
+    pub fn direct_horizontal(&self) -> f64 {
+        self.direct_normal * self.cos_zenith
+    }
+
+    /// Total radiation on a horizontal surface [W/m2]
+    pub fn global_horizontal(&self) -> f64 {
+        self.direct_horizontal() + self.diffuse_horizontal
+    }
+}
+
+// Function to check for leap years
+fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
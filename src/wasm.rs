@@ -0,0 +1,87 @@
+//! Module containing the WebAssembly bindings
+//! for the citizen-science web app
+
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use serde_wasm_bindgen::Serializer;
+use wasm_bindgen::prelude::*;
+
+use crate::heat_indices::{self, StressCategory};
+use crate::service::{CategoryOutput, HeatStressOutput, ObservationInput};
+
+/// Observation as entered in the web app, with
+/// the camelCase keys of JavaScript
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JsObservationInput {
+    air_temperature: f32,
+    wind_speed: f32,
+    relative_humidity: f32,
+    #[serde(default)]
+    binnenstad: bool,
+    #[serde(default)]
+    park: bool,
+    #[serde(default)]
+    shade: bool,
+    #[serde(default)]
+    timestamp: Option<DateTime<Utc>>,
+}
+
+impl From<JsObservationInput> for ObservationInput {
+    fn from(input: JsObservationInput) -> ObservationInput {
+        ObservationInput {
+            air_temperature: input.air_temperature,
+            wind_speed: input.wind_speed,
+            relative_humidity: input.relative_humidity,
+            binnenstad: input.binnenstad,
+            park: input.park,
+            shade: input.shade,
+            timestamp: input.timestamp,
+        }
+    }
+}
+
+// Function to convert an output to plain JavaScript
+// objects, the flattened fields being serialized as
+// a map that would otherwise become a Map
+fn to_value<T: Serialize>(output: &T) -> Result<JsValue, JsError> {
+    Ok(output.serialize(&Serializer::json_compatible())?)
+}
+
+/// Calculates experienced Heat Stress from an
+/// observation object, returning the UTCI, mean
+/// radiant temperature and stress category
+#[wasm_bindgen(js_name = experiencedHeatStress)]
+pub fn experienced_heat_stress(input: JsValue) -> Result<JsValue, JsError> {
+    let input: JsObservationInput = serde_wasm_bindgen::from_value(input)?;
+    let input = ObservationInput::from(input);
+    let timestamp = match input.timestamp {
+        Some(timestamp) => timestamp,
+        None => Utc
+            .timestamp_millis_opt(js_sys::Date::now() as i64)
+            .single()
+            .ok_or_else(|| JsError::new("Invalid clock"))?,
+    };
+
+    let heat_stress =
+        heat_indices::experienced_heat_stress(input.observation(timestamp))?;
+    to_value(&HeatStressOutput::from(heat_stress))
+}
+
+/// Category of the UTCI scale of a UTCI value
+#[wasm_bindgen(js_name = stressCategory)]
+pub fn stress_category(utci: f32) -> Result<JsValue, JsError> {
+    let output = CategoryOutput::from(StressCategory::from_utci(utci));
+    to_value(&output)
+}
+
+/// Every category of the UTCI scale, from
+/// cold to hot, for drawing a legend
+#[wasm_bindgen(js_name = stressCategories)]
+pub fn stress_categories() -> Result<JsValue, JsError> {
+    let output: Vec<CategoryOutput> = StressCategory::ALL
+        .iter()
+        .map(|category| CategoryOutput::from(*category))
+        .collect();
+    to_value(&output)
+}
@@ -0,0 +1,111 @@
+use chrono::{Duration, TimeZone, Utc};
+use utci::{
+    experienced_heat_stress, experienced_heat_stress_batch, Error, Location,
+    Observation, ObservationColumns,
+};
+
+#[test]
+fn matches_single_observations() {
+    let start = Utc.with_ymd_and_hms(2021, 7, 1, 0, 0, 0).unwrap();
+    let timestamp: Vec<_> = (0..144)
+        .map(|step| start + Duration::minutes(10 * step))
+        .collect();
+    let air_temperature: Vec<f32> =
+        (0..144).map(|step| 15.0 + (step % 20) as f32).collect();
+    let wind_speed = vec![2.0; 144];
+    let relative_humidity = vec![60.0; 144];
+    let location: Vec<usize> = (0..144).map(|step| step % 2).collect();
+    let locations = [
+        Location::groningen(true, false, false),
+        Location::groningen(false, true, true),
+    ];
+
+    let columns = ObservationColumns {
+        air_temperature: &air_temperature,
+        wind_speed: &wind_speed,
+        relative_humidity: &relative_humidity,
+        timestamp: &timestamp,
+        location: &location,
+        global_radiation: &[],
+    };
+    let mut output = vec![0.0; 144];
+    let invalid =
+        experienced_heat_stress_batch(&columns, &locations, &mut output)
+            .unwrap();
+    assert_eq!(invalid, 0);
+
+    for index in 0..144 {
+        let observation = Observation {
+            air_temperature: air_temperature[index],
+            wind_speed: wind_speed[index],
+            relative_humidity: relative_humidity[index],
+            binnenstad: location[index] == 0,
+            park: location[index] == 1,
+            shade: location[index] == 1,
+            timestamp: timestamp[index],
+        };
+        let expected = experienced_heat_stress(observation).unwrap().utci;
+        assert_eq!(output[index], expected);
+    }
+}
+
+#[test]
+fn invalid_observations_are_nan() {
+    let timestamp = [Utc.with_ymd_and_hms(2021, 7, 1, 12, 0, 0).unwrap(); 2];
+    let columns = ObservationColumns {
+        air_temperature: &[20.0, 80.0],
+        wind_speed: &[2.0, 2.0],
+        relative_humidity: &[50.0, 50.0],
+        timestamp: &timestamp,
+        location: &[],
+        global_radiation: &[],
+    };
+    let locations = [Location::groningen(false, false, false)];
+    let mut output = [0.0; 2];
+    let invalid =
+        experienced_heat_stress_batch(&columns, &locations, &mut output)
+            .unwrap();
+    assert_eq!(invalid, 1);
+    assert!(output[0].is_finite());
+    assert!(output[1].is_nan());
+
+    let mut short = [0.0; 1];
+    assert_eq!(
+        experienced_heat_stress_batch(&columns, &locations, &mut short),
+        Err(Error::InvalidInput),
+    );
+    assert_eq!(
+        experienced_heat_stress_batch(&columns, &[], &mut output),
+        Err(Error::InvalidInput),
+    );
+}
+
+#[test]
+fn uses_measured_radiation() {
+    let timestamp = [Utc.with_ymd_and_hms(2021, 7, 1, 12, 0, 0).unwrap(); 3];
+    let measured = ObservationColumns {
+        air_temperature: &[25.0; 3],
+        wind_speed: &[2.0; 3],
+        relative_humidity: &[50.0; 3],
+        timestamp: &timestamp,
+        location: &[],
+        global_radiation: &[100.0, 800.0, -5.0],
+    };
+    let clear_sky = ObservationColumns {
+        global_radiation: &[],
+        ..measured
+    };
+    let locations = [Location::groningen(false, false, false)];
+
+    let mut output = [0.0; 3];
+    let invalid =
+        experienced_heat_stress_batch(&measured, &locations, &mut output)
+            .unwrap();
+    let mut expected = [0.0; 3];
+    experienced_heat_stress_batch(&clear_sky, &locations, &mut expected)
+        .unwrap();
+    assert_eq!(invalid, 1);
+    assert!(output[0] < output[1]);
+    assert!(output[0] < expected[0]);
+    assert!(output[2].is_nan());
+}
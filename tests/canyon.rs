@@ -0,0 +1,46 @@
+use chrono::{TimeZone, Utc};
+use utci::{
+    experienced_heat_stress_in_canyon, CanyonGeometry, Error, Observation,
+};
+
+#[test]
+fn wider_streets_see_more_sky() {
+    let narrow = CanyonGeometry::GRONINGEN_BINNENSTAD;
+    let wide = CanyonGeometry {
+        street_width: 2.0 * narrow.street_width,
+        ..narrow
+    };
+    assert!(wide.sky_view_factor() > narrow.sky_view_factor());
+    assert!(narrow.sky_view_factor() > 0.0 && narrow.sky_view_factor() < 1.0);
+}
+
+#[test]
+fn presets_are_valid() {
+    assert!(CanyonGeometry::GRONINGEN_BINNENSTAD.validate().is_ok());
+    assert!(CanyonGeometry::GRONINGEN_RESIDENTIAL.validate().is_ok());
+    assert_eq!(
+        CanyonGeometry::groningen(true),
+        CanyonGeometry::GRONINGEN_BINNENSTAD,
+    );
+}
+
+#[test]
+fn rejects_invalid_geometry() {
+    let observation = Observation {
+        air_temperature: 20.0,
+        wind_speed: 2.0,
+        relative_humidity: 50.0,
+        binnenstad: false,
+        park: false,
+        shade: false,
+        timestamp: Utc.with_ymd_and_hms(2021, 7, 1, 12, 0, 0).unwrap(),
+    };
+    let canyon = CanyonGeometry {
+        street_width: 0.0,
+        ..CanyonGeometry::GRONINGEN_RESIDENTIAL
+    };
+    assert_eq!(
+        experienced_heat_stress_in_canyon(observation, &canyon),
+        Err(Error::InvalidCanyonGeometry),
+    );
+}
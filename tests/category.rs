@@ -0,0 +1,51 @@
+use utci::StressCategory;
+
+#[test]
+fn classifies_on_the_thresholds() {
+    assert_eq!(
+        StressCategory::from_utci(-45.0),
+        StressCategory::ExtremeColdStress,
+    );
+    assert_eq!(
+        StressCategory::from_utci(-40.0),
+        StressCategory::VeryStrongColdStress,
+    );
+    assert_eq!(
+        StressCategory::from_utci(9.0),
+        StressCategory::NoThermalStress,
+    );
+    assert_eq!(
+        StressCategory::from_utci(26.0),
+        StressCategory::NoThermalStress,
+    );
+    assert_eq!(
+        StressCategory::from_utci(26.1),
+        StressCategory::ModerateHeatStress,
+    );
+    assert_eq!(
+        StressCategory::from_utci(46.1),
+        StressCategory::ExtremeHeatStress,
+    );
+}
+
+#[test]
+fn categories_follow_the_scale() {
+    let midpoints =
+        [-45.0, -33.0, -20.0, -6.0, 4.0, 17.0, 29.0, 35.0, 42.0, 50.0];
+    for (utci, category) in midpoints.iter().zip(StressCategory::ALL.iter()) {
+        assert_eq!(StressCategory::from_utci(*utci), *category);
+    }
+    assert!(StressCategory::ALL.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+#[test]
+fn names_and_colours() {
+    let category = StressCategory::StrongHeatStress;
+    assert_eq!(category.to_string(), "strong heat stress");
+    assert_eq!(category.dutch_name(), "sterke hittestress");
+    for category in StressCategory::ALL.iter() {
+        let colour = category.colour();
+        assert_eq!(colour.len(), 7);
+        assert!(u32::from_str_radix(&colour[1..], 16).is_ok());
+    }
+}
@@ -0,0 +1,59 @@
+#![cfg(feature = "cli")]
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+fn utci() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_utci"))
+}
+
+#[test]
+fn prints_utci_and_category() {
+    let output = utci()
+        .args(["25", "2", "50", "--time", "2021-07-01T12:00:00Z"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let (utci, category) = stdout.trim().split_once('\t').unwrap();
+    assert!(utci.parse::<f32>().is_ok());
+    assert!(category.ends_with("stress"));
+}
+
+#[test]
+fn reads_rows_from_stdin() {
+    let mut child = utci()
+        .arg("--json")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(
+            b"air_temperature,wind_speed,relative_humidity\n\
+              25,2,50,1,0,0,2021-07-01T12:00:00Z\n\
+              \n\
+              \"25\", 2 ,50,,,,\"2021-07-01T12:00:00Z\"\n\
+              80,1,50\n",
+        )
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(!output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let rows: Vec<serde_json::Value> = stdout
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(rows.len(), 3);
+    assert!(rows[0]["utci"].is_number());
+    assert!(rows[0]["category"].is_string());
+    assert!(rows[1]["utci"].is_number());
+    assert!(rows[2]["error"].is_string());
+    // failures are only reported in their row
+    assert!(output.stderr.is_empty());
+}
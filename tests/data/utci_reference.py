@@ -0,0 +1,114 @@
+"""Generates the subsample of tests/data/utci_reference.csv
+
+Evaluates UTCI_approx (Broede et al., 2012) as written in the
+published Fortran: the saturation vapour pressure over water of
+Hardy (1998) and a plain sum over the 210 polynomial terms, without
+any of the crate's evaluation code. Only the coefficient table is
+read from src/heat_indices/coefficients.rs, which is pinned by the
+published reference values at the top of the fixture.
+
+    python3 tests/data/utci_reference.py > tests/data/utci_reference.csv
+"""
+
+import math
+import pathlib
+import re
+
+ROOT = pathlib.Path(__file__).resolve().parents[2]
+
+# Published values of the official reference table
+PUBLISHED = [
+    (25.0, 25.0, 1.0, 50.0, 24.6),
+    (25.0, 27.0, 1.0, 50.0, 25.2),
+    (19.0, 24.0, 1.0, 50.0, 20.0),
+    (19.0, 14.0, 1.0, 50.0, 16.8),
+    (27.0, 22.0, 1.0, 50.0, 25.4),
+    (27.0, 22.0, 10.0, 50.0, 20.0),
+    (27.0, 22.0, 16.0, 50.0, 15.8),
+]
+
+# Subsample spanning the range of the polynomial
+AIR_TEMPERATURES = [-50.0, -40.0, -25.0, -10.0, 0.0, 10.0, 20.0, 30.0, 40.0, 50.0]
+TEMPERATURE_DIFFERENCES = [-30.0, -10.0, 0.0, 15.0, 35.0, 70.0]
+WIND_SPEEDS = [0.5, 1.0, 3.0, 6.0, 10.0, 17.0]
+RELATIVE_HUMIDITIES = [5.0, 25.0, 50.0, 75.0, 100.0]
+
+# Highest vapour pressure of the polynomial [kPa]
+MAX_VAPOUR_PRESSURE = 5.0
+
+TOLERANCE = 0.1
+
+
+def coefficients():
+    source = (ROOT / "src/heat_indices/coefficients.rs").read_text()
+    terms = re.findall(
+        r"\(([-+0-9.e]+), \[(\d), (\d), (\d), (\d)\]\)", source
+    )
+    assert len(terms) == 210
+    return [(float(c), tuple(int(p) for p in powers)) for c, *powers in terms]
+
+
+def saturation_vapour_pressure(ta):
+    """Saturation vapour pressure over water [hPa]"""
+    g = [
+        -2.8365744e3,
+        -6.028076559e3,
+        1.954263612e1,
+        -2.737830188e-2,
+        1.6261698e-5,
+        7.0229056e-10,
+        -1.8680009e-13,
+    ]
+    tk = ta + 273.15
+    es = 2.7150305 * math.log(tk)
+    for i, gi in enumerate(g):
+        es += gi * tk ** (i - 2)
+    return math.exp(es) * 0.01
+
+
+def vapour_pressure(ta, rh):
+    """Vapour pressure [kPa]"""
+    return saturation_vapour_pressure(ta) * rh / 100.0 / 10.0
+
+
+def utci_approx(terms, ta, tmrt, va, rh):
+    inputs = (ta, va, tmrt - ta, vapour_pressure(ta, rh))
+    total = ta
+    for coefficient, powers in terms:
+        term = coefficient
+        for value, power in zip(inputs, powers):
+            term *= value**power
+        total += term
+    return total
+
+
+def main():
+    terms = coefficients()
+    for ta, tmrt, va, rh, utci in PUBLISHED:
+        assert abs(utci_approx(terms, ta, tmrt, va, rh) - utci) <= TOLERANCE
+
+    print("# UTCI reference values, rounded to 0.1 C")
+    print("# published rows of the official reference table first,")
+    print("# then a subsample across the range of the polynomial")
+    print("# generated by utci_reference.py")
+    print("# UTCI_REFERENCE_TABLE may name a copy of the full")
+    print("# official table in the same columns to run instead")
+    print(
+        "# air_temperature,radiant_temperature,wind_speed,"
+        "relative_humidity,utci,tolerance"
+    )
+    for ta, tmrt, va, rh, utci in PUBLISHED:
+        print(f"{ta},{tmrt},{va},{rh},{utci},{TOLERANCE}")
+    for ta in AIR_TEMPERATURES:
+        for difference in TEMPERATURE_DIFFERENCES:
+            for va in WIND_SPEEDS:
+                for rh in RELATIVE_HUMIDITIES:
+                    if vapour_pressure(ta, rh) > MAX_VAPOUR_PRESSURE:
+                        continue
+                    tmrt = ta + difference
+                    utci = utci_approx(terms, ta, tmrt, va, rh)
+                    print(f"{ta},{tmrt},{va},{rh},{utci:.1f},{TOLERANCE}")
+
+
+if __name__ == "__main__":
+    main()
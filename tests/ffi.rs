@@ -0,0 +1,104 @@
+#![cfg(feature = "ffi")]
+
+use std::ffi::CStr;
+use std::fs;
+use std::os::raw::c_int;
+use std::ptr;
+
+use utci::ffi::{
+    utci_experienced_heat_stress, utci_experienced_heat_stress_batch,
+    utci_status_message, utci_stress_category, utci_stress_category_name,
+    UtciHeatStress, UtciObservation, UtciStatus,
+};
+
+// 2021-07-01 12:00:00 UTC
+const NOON: i64 = 1_625_140_800;
+
+fn observation(air_temperature: f32) -> UtciObservation {
+    UtciObservation {
+        air_temperature,
+        wind_speed: 2.0,
+        relative_humidity: 50.0,
+        binnenstad: true,
+        park: false,
+        shade: false,
+        timestamp: NOON,
+    }
+}
+
+#[test]
+fn reports_errors_as_status() {
+    let mut output = UtciHeatStress::default();
+    let status =
+        unsafe { utci_experienced_heat_stress(observation(25.0), &mut output) };
+    assert_eq!(status, UtciStatus::UtciOk);
+    assert_eq!(output.category, utci_stress_category(output.utci));
+
+    let status =
+        unsafe { utci_experienced_heat_stress(observation(80.0), &mut output) };
+    assert_eq!(status, UtciStatus::UtciInvalidAirTemperature);
+    let message =
+        unsafe { CStr::from_ptr(utci_status_message(status as c_int)) };
+    assert!(message.to_str().unwrap().starts_with("Air Temperature"));
+
+    let status = unsafe {
+        utci_experienced_heat_stress(observation(25.0), ptr::null_mut())
+    };
+    assert_eq!(status, UtciStatus::UtciNullPointer);
+}
+
+#[test]
+fn batch_over_raw_pointers() {
+    let air_temperature = [20.0, 25.0, 90.0];
+    let wind_speed = [2.0; 3];
+    let relative_humidity = [50.0; 3];
+    let timestamp = [NOON; 3];
+    let mut output = [0.0; 3];
+    let mut invalid = 0;
+    let status = unsafe {
+        utci_experienced_heat_stress_batch(
+            air_temperature.as_ptr(),
+            wind_speed.as_ptr(),
+            relative_humidity.as_ptr(),
+            timestamp.as_ptr(),
+            3,
+            false,
+            false,
+            false,
+            output.as_mut_ptr(),
+            &mut invalid,
+        )
+    };
+    assert_eq!(status, UtciStatus::UtciOk);
+    assert_eq!(invalid, 1);
+    assert!(output[0].is_finite() && output[2].is_nan());
+}
+
+#[test]
+fn category_names() {
+    let name = unsafe { CStr::from_ptr(utci_stress_category_name(9)) };
+    assert_eq!(name.to_str().unwrap(), "extreme heat stress");
+    assert!(utci_stress_category_name(10).is_null());
+}
+
+#[test]
+fn unknown_status_has_message() {
+    for status in [-1, 21, c_int::MAX] {
+        let message = unsafe { CStr::from_ptr(utci_status_message(status)) };
+        assert_eq!(message.to_str().unwrap(), "Unknown status");
+    }
+}
+
+#[test]
+fn header_is_up_to_date() {
+    let generated = fs::read_to_string(env!("UTCI_GENERATED_HEADER")).unwrap();
+    let committed = fs::read_to_string(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/include/utci.h"
+    ))
+    .unwrap();
+    assert_eq!(
+        committed, generated,
+        "regenerate with UTCI_HEADER=include/utci.h"
+    );
+}
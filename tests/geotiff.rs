@@ -0,0 +1,40 @@
+#![cfg(feature = "geotiff")]
+
+use utci::raster::GeoKeys;
+use utci::{read_geotiff, write_geotiff, GeoTransform, Raster};
+
+#[test]
+fn keeps_placement_and_no_data() {
+    let transform = GeoTransform {
+        origin_x: 233_000.0,
+        origin_y: 582_000.0,
+        pixel_width: 0.5,
+        pixel_height: 0.5,
+    };
+    let mut raster = Raster::from_vec(
+        3,
+        2,
+        vec![1.0, 2.5, f32::NAN, 4.0, 5.0, 6.0],
+        transform,
+    )
+    .unwrap();
+    // projected Amersfoort / RD New
+    raster.geo_keys = GeoKeys {
+        directory: vec![1, 1, 0, 1, 3072, 0, 1, 28992],
+        doubles: Vec::new(),
+        ascii: String::new(),
+    };
+
+    let path = std::env::temp_dir().join("utci_geotiff_roundtrip.tif");
+    write_geotiff(&path, &raster).unwrap();
+    let read = read_geotiff(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(read.width(), 3);
+    assert_eq!(read.height(), 2);
+    assert_eq!(read.transform, transform);
+    assert_eq!(read.geo_keys, raster.geo_keys);
+    assert_eq!(read.get(1, 0), Some(2.5));
+    assert!(read.get(2, 0).unwrap().is_nan());
+    assert_eq!(read.get(2, 1), Some(6.0));
+}
@@ -0,0 +1,131 @@
+#![cfg(feature = "netcdf")]
+
+use std::convert::TryFrom;
+
+use chrono::{TimeZone, Utc};
+use utci::series::TemperatureUnit;
+use utci::{
+    experienced_heat_stress, process_netcdf, read_grid, Grid, GridMapping,
+    Observation,
+};
+
+// Function to write a small ERA5 style extract
+// over two hours and a 2 by 3 grid
+fn write_input(path: &std::path::Path) {
+    let mut file = netcdf::create(path).unwrap();
+    file.add_dimension("time", 2).unwrap();
+    file.add_dimension("latitude", 2).unwrap();
+    file.add_dimension("longitude", 3).unwrap();
+
+    let mut time = file.add_variable::<i32>("time", &["time"]).unwrap();
+    time.put_attribute("units", "hours since 1900-01-01 00:00:00.0")
+        .unwrap();
+    // 2021-07-01 at 11 and 12 UTC
+    time.put_values(&[1_065_035, 1_065_036], ..).unwrap();
+
+    let mut latitude =
+        file.add_variable::<f32>("latitude", &["latitude"]).unwrap();
+    latitude.put_values(&[53.25f32, 53.0], ..).unwrap();
+    let mut longitude = file
+        .add_variable::<f32>("longitude", &["longitude"])
+        .unwrap();
+    longitude.put_values(&[6.25f32, 6.5, 6.75], ..).unwrap();
+
+    let dimensions = ["time", "latitude", "longitude"];
+    let mut temperature = file.add_variable::<i16>("t2m", &dimensions).unwrap();
+    temperature.put_attribute("scale_factor", 0.01).unwrap();
+    temperature.put_attribute("add_offset", 290.0).unwrap();
+    temperature.put_attribute("_FillValue", -32767i16).unwrap();
+    let mut packed: Vec<i16> = (0..12).map(|index| 800 + index * 10).collect();
+    packed[11] = -32767;
+    temperature.put_values(&packed, ..).unwrap();
+
+    let mut wind = file.add_variable::<f32>("si10", &dimensions).unwrap();
+    wind.put_values(&[2.0f32; 12], ..).unwrap();
+    let mut humidity = file.add_variable::<f32>("r2", &dimensions).unwrap();
+    humidity.put_values(&[55.0f32; 12], ..).unwrap();
+}
+
+fn mapping() -> GridMapping {
+    GridMapping::from_toml(
+        r#"
+        air_temperature = "t2m"
+        wind_speed = "si10"
+        relative_humidity = "r2"
+        temperature_unit = "kelvin"
+        "#,
+    )
+    .unwrap()
+}
+
+#[test]
+fn reads_packed_variables() {
+    let directory = std::env::temp_dir();
+    let input = directory.join("utci_gridded_read.nc");
+    write_input(&input);
+
+    let mapping = mapping();
+    assert_eq!(mapping.temperature_unit, TemperatureUnit::Kelvin);
+    let grid = read_grid(&input, &mapping).unwrap();
+    assert_eq!((grid.rows, grid.columns, grid.len()), (2, 3, 12));
+    assert!(!grid.is_curvilinear());
+    assert_eq!(
+        grid.time[1],
+        Utc.with_ymd_and_hms(2021, 7, 1, 12, 0, 0).unwrap()
+    );
+    assert!((grid.air_temperature[0] - 24.85).abs() < 1e-3);
+    assert!(grid.air_temperature[11].is_nan());
+    assert_eq!(grid.coordinates(1, 2), (53.0, 6.75));
+}
+
+#[test]
+fn matches_single_observations() {
+    let grid = Grid {
+        time: vec![Utc.with_ymd_and_hms(2021, 7, 1, 12, 0, 0).unwrap()],
+        latitude: vec![53.2194],
+        longitude: vec![6.5665, 6.5665],
+        rows: 1,
+        columns: 2,
+        air_temperature: vec![25.0, 80.0],
+        wind_speed: vec![2.0, 2.0],
+        relative_humidity: vec![50.0, 50.0],
+        global_radiation: Vec::new(),
+    };
+    let (utci, invalid) = grid.utci(true, false, false).unwrap();
+    let expected = experienced_heat_stress(Observation {
+        air_temperature: 25.0,
+        wind_speed: 2.0,
+        relative_humidity: 50.0,
+        binnenstad: true,
+        park: false,
+        shade: false,
+        timestamp: grid.time[0],
+    })
+    .unwrap();
+    assert_eq!(invalid, 1);
+    assert_eq!(utci[0], expected.utci);
+    assert!(utci[1].is_nan());
+}
+
+#[test]
+fn writes_cf_output() {
+    let directory = std::env::temp_dir();
+    let input = directory.join("utci_gridded_input.nc");
+    let output = directory.join("utci_gridded_output.nc");
+    write_input(&input);
+
+    let invalid = process_netcdf(&input, &output, &mapping()).unwrap();
+    assert_eq!(invalid, 1);
+
+    let file = netcdf::open(&output).unwrap();
+    let utci = file.variable("utci").unwrap();
+    let units =
+        String::try_from(utci.attribute_value("units").unwrap().unwrap())
+            .unwrap();
+    assert_eq!(units, "degC");
+    let values = utci.get_values::<f32, _>(..).unwrap();
+    assert_eq!(values.len(), 12);
+    assert_eq!(values[11], -9999.0);
+    assert!(values[..11].iter().all(|value| value.is_finite()));
+    assert!(file.variable("utci_category").is_some());
+}
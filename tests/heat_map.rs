@@ -0,0 +1,116 @@
+use chrono::{TimeZone, Utc};
+use utci::{
+    heat_stress_map, Error, GeoTransform, HeatMap, HeatMapSettings, Raster,
+    Shading, SurfaceType, Weather,
+};
+
+const SIZE: usize = 21;
+
+// Function to describe a street running north
+// to south between two blocks of 20 m, with a
+// tree of 8 m in the middle of the street
+fn street() -> (Raster<u8>, Raster<f32>) {
+    let transform = GeoTransform {
+        origin_x: 233_000.0,
+        origin_y: 582_000.0,
+        pixel_width: 1.0,
+        pixel_height: 1.0,
+    };
+    let mut land_cover =
+        Raster::filled(SIZE, SIZE, SurfaceType::Paved as u8, transform);
+    let mut heights = Raster::filled(SIZE, SIZE, 0.0, transform);
+    for row in 0..SIZE {
+        for column in (0..5).chain(16..SIZE) {
+            land_cover.set(column, row, SurfaceType::Building as u8);
+            heights.set(column, row, 20.0);
+        }
+    }
+    land_cover.set(10, 5, SurfaceType::Tree as u8);
+    heights.set(10, 5, 8.0);
+    (land_cover, heights)
+}
+
+fn weather() -> Weather {
+    Weather {
+        air_temperature: 28.0,
+        wind_speed: 2.0,
+        relative_humidity: 50.0,
+        timestamp: Utc.with_ymd_and_hms(2021, 7, 1, 12, 0, 0).unwrap(),
+        global_radiation: None,
+    }
+}
+
+#[test]
+fn maps_street_canyon() {
+    let (land_cover, heights) = street();
+    let settings = HeatMapSettings::default();
+    let heat_map =
+        heat_stress_map(&land_cover, &heights, &weather(), &settings).unwrap();
+    assert!(heat_map.utci.is_aligned(&heights));
+
+    // the street is 11 m wide between walls of
+    // 20 m, and open at both ends
+    let canyon = (20.0f32 / 5.5).atan().cos();
+    let street_svf = heat_map.sky_view_factor.get(10, 15).unwrap();
+    assert!(street_svf > canyon && street_svf < 0.5);
+    assert!(heat_map.utci.get(10, 15).unwrap().is_finite());
+    assert_eq!(heat_map.surface.get(10, 15), Some(SurfaceType::Paved as u8));
+
+    // roofs are left out of the map
+    assert!(heat_map.sky_view_factor.get(0, 0).unwrap().is_nan());
+    assert!(heat_map.utci.get(0, 0).unwrap().is_nan());
+
+    assert_eq!(heat_map.shading.get(10, 5), Some(Shading::Tree as u8));
+}
+
+#[test]
+fn trees_hide_part_of_sky() {
+    let (mut land_cover, mut heights) = street();
+    let settings = HeatMapSettings::default();
+    let with_tree =
+        heat_stress_map(&land_cover, &heights, &weather(), &settings).unwrap();
+    land_cover.set(10, 5, SurfaceType::Paved as u8);
+    heights.set(10, 5, 0.0);
+    let without_tree =
+        heat_stress_map(&land_cover, &heights, &weather(), &settings).unwrap();
+
+    let svf = |heat_map: &HeatMap| heat_map.sky_view_factor.get(10, 6).unwrap();
+    assert!(svf(&with_tree) < svf(&without_tree));
+}
+
+#[test]
+fn open_field_sees_whole_sky() {
+    let transform = GeoTransform::default();
+    let land_cover = Raster::filled(5, 5, SurfaceType::Grass as u8, transform);
+    let heights = Raster::filled(5, 5, 0.0, transform);
+    let heat_map = heat_stress_map(
+        &land_cover,
+        &heights,
+        &weather(),
+        &HeatMapSettings::default(),
+    )
+    .unwrap();
+    assert!(heat_map
+        .sky_view_factor
+        .data()
+        .iter()
+        .all(|svf| *svf == 1.0));
+    assert!(heat_map
+        .shading
+        .data()
+        .iter()
+        .all(|shading| *shading == Shading::Sunlit as u8));
+}
+
+#[test]
+fn rejects_misaligned_rasters() {
+    let (land_cover, _) = street();
+    let heights = Raster::filled(SIZE, SIZE, 0.0, GeoTransform::default());
+    let output = heat_stress_map(
+        &land_cover,
+        &heights,
+        &weather(),
+        &HeatMapSettings::default(),
+    );
+    assert_eq!(output, Err(Error::InvalidInput));
+}
@@ -0,0 +1,110 @@
+use chrono::{TimeZone, Utc};
+use utci::{
+    apparent_temperature, heat_index, humidex, wet_bulb_globe_temperature,
+    wet_bulb_globe_temperature_at_site,
+    wet_bulb_globe_temperature_with_radiation, Error, Observation, Site,
+};
+
+fn observation(
+    air_temperature: f32,
+    relative_humidity: f32,
+    hour: u32,
+) -> Observation {
+    Observation {
+        air_temperature,
+        wind_speed: 2.0,
+        relative_humidity,
+        binnenstad: false,
+        park: true,
+        shade: false,
+        timestamp: Utc.with_ymd_and_hms(2021, 7, 1, hour, 0, 0).unwrap(),
+    }
+}
+
+#[test]
+fn heat_index_matches_nws_table() {
+    // 90 F at 70 % is 106 F
+    let rothfusz = heat_index(observation(32.22, 70.0, 12)).unwrap();
+    assert!((rothfusz - 41.1).abs() < 0.1);
+    // 80 F at 40 % is 80 F by the simple formula
+    let simple = heat_index(observation(26.67, 40.0, 12)).unwrap();
+    assert!((simple - 26.7).abs() < 0.5);
+    // dry air lowers the index
+    let dry = heat_index(observation(37.78, 10.0, 12)).unwrap();
+    assert!(dry < 37.78);
+}
+
+#[test]
+fn humidex_matches_canadian_table() {
+    // 30 C with a dew point of 15 C is 34
+    let output = humidex(observation(30.0, 40.2, 12)).unwrap();
+    assert!((output - 34.0).abs() < 0.5);
+}
+
+#[test]
+fn apparent_temperature_follows_wind() {
+    let calm = apparent_temperature(observation(30.0, 50.0, 12)).unwrap();
+    assert!((calm - 31.6).abs() < 0.1);
+    let windy = apparent_temperature(Observation {
+        wind_speed: 8.0,
+        ..observation(30.0, 50.0, 12)
+    })
+    .unwrap();
+    assert!(windy < calm);
+}
+
+#[test]
+fn wbgt_follows_sunlight() {
+    let sun = wet_bulb_globe_temperature(observation(30.0, 50.0, 12)).unwrap();
+    let shade = wet_bulb_globe_temperature(Observation {
+        shade: true,
+        ..observation(30.0, 50.0, 12)
+    })
+    .unwrap();
+    let night = wet_bulb_globe_temperature(observation(30.0, 50.0, 0)).unwrap();
+    assert!(sun > shade && shade > night);
+    assert!(sun > 27.0 && sun < 31.0);
+    assert!(night > 22.0 && night < 26.0);
+}
+
+#[test]
+fn wbgt_uses_measured_radiation() {
+    let observation = observation(30.0, 50.0, 12);
+    let site = Site::groningen(false, true);
+    let clear = wet_bulb_globe_temperature_at_site(observation, &site).unwrap();
+    assert_eq!(
+        wet_bulb_globe_temperature_with_radiation(observation, &site, None),
+        Ok(clear)
+    );
+
+    let overcast = wet_bulb_globe_temperature_with_radiation(
+        observation,
+        &site,
+        Some(100.0),
+    )
+    .unwrap();
+    assert!(overcast < clear - 1.0);
+    assert_eq!(
+        wet_bulb_globe_temperature_with_radiation(
+            observation,
+            &site,
+            Some(-5.0)
+        ),
+        Err(Error::InvalidRadiantFlux)
+    );
+}
+
+#[test]
+fn shares_validation() {
+    let observation = observation(55.0, 50.0, 12);
+    assert_eq!(heat_index(observation), Err(Error::InvalidAirTemperature));
+    assert_eq!(humidex(observation), Err(Error::InvalidAirTemperature));
+    assert_eq!(
+        apparent_temperature(observation),
+        Err(Error::InvalidAirTemperature)
+    );
+    assert_eq!(
+        wet_bulb_globe_temperature(observation),
+        Err(Error::InvalidAirTemperature)
+    );
+}
@@ -0,0 +1,84 @@
+use utci::knmi::KNMI_EELDE;
+use utci::{experienced_heat_stress, read_knmi_hourly, Error, KnmiRecord};
+
+const EELDE: &str = include_str!("data/knmi_eelde.txt");
+
+#[test]
+fn converts_units() {
+    let records = read_knmi_hourly(EELDE.as_bytes()).unwrap();
+    assert_eq!(records.len(), 5);
+
+    let first = records[0].as_ref().unwrap();
+    assert_eq!(first.station, KNMI_EELDE);
+    assert_eq!(first.air_temperature, Some(25.0));
+    assert_eq!(first.wind_speed, Some(2.0));
+    assert_eq!(first.relative_humidity, Some(50.0));
+    assert!((first.global_radiation.unwrap() - 800.0).abs() < 1e-3);
+    assert_eq!(first.timestamp.to_rfc3339(), "2021-07-01T12:00:00+00:00");
+}
+
+#[test]
+fn hour_24_ends_at_midnight() {
+    let records = read_knmi_hourly(EELDE.as_bytes()).unwrap();
+    let last = records[3].as_ref().unwrap();
+    assert_eq!(last.timestamp.to_rfc3339(), "2021-07-02T00:00:00+00:00");
+}
+
+#[test]
+fn reports_missing_and_invalid_rows() {
+    let records = read_knmi_hourly(EELDE.as_bytes()).unwrap();
+    let missing = records[2].as_ref().unwrap();
+    assert_eq!(missing.air_temperature, None);
+    assert_eq!(
+        missing.heat_stress(false, false, false),
+        Err(Error::MissingValue(String::from("T")))
+    );
+
+    let invalid = records[4].as_ref().unwrap_err();
+    assert_eq!(invalid.error, Error::InvalidTimestamp);
+    assert_eq!(invalid.line, 19);
+}
+
+#[test]
+fn calculates_heat_stress() {
+    let records = read_knmi_hourly(EELDE.as_bytes()).unwrap();
+    let heat_stress = records[0]
+        .as_ref()
+        .unwrap()
+        .heat_stress(true, false, false)
+        .unwrap();
+    assert!(heat_stress.utci > 25.0 && heat_stress.utci < 40.0);
+}
+
+#[test]
+fn uses_measured_radiation() {
+    let records = read_knmi_hourly(EELDE.as_bytes()).unwrap();
+    let sunny = *records[0].as_ref().unwrap();
+    let overcast = KnmiRecord {
+        global_radiation: Some(100.0),
+        ..sunny
+    };
+    let unmeasured = KnmiRecord {
+        global_radiation: None,
+        ..sunny
+    };
+
+    let sunny_utci = sunny.heat_stress(true, false, false).unwrap().utci;
+    let overcast_utci = overcast.heat_stress(true, false, false).unwrap().utci;
+    assert!(overcast_utci < sunny_utci - 1.0);
+    assert_eq!(
+        unmeasured.heat_stress(true, false, false),
+        experienced_heat_stress(
+            unmeasured.observation(true, false, false).unwrap()
+        )
+    );
+}
+
+#[test]
+fn requires_header() {
+    let input = "  280,20210701,   12,  230,   20\n";
+    assert_eq!(
+        read_knmi_hourly(input.as_bytes()).unwrap_err(),
+        Error::MissingColumn(String::from("STN"))
+    );
+}
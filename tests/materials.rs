@@ -0,0 +1,124 @@
+use chrono::{TimeZone, Utc};
+use utci::{
+    experienced_heat_stress_at_site, Error, Material, MaterialLibrary,
+    Observation, Site, Surfaces,
+};
+
+#[test]
+fn loads_toml_file() {
+    let library = MaterialLibrary::load(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/data/materials.toml"
+    ))
+    .unwrap();
+    assert_eq!(library.get("klinkers"), Some(Material::KLINKERS));
+    assert_eq!(
+        library.get("basalt_paving"),
+        Some(Material {
+            emissivity: 0.93,
+            albedo: 0.12,
+        }),
+    );
+    assert_eq!(library.get("asphalt"), None);
+}
+
+#[test]
+fn parses_json() {
+    let library = MaterialLibrary::from_json(
+        r#"{"green_roof": {"emissivity": 0.95, "albedo": 0.2}}"#,
+    )
+    .unwrap();
+    assert_eq!(library.get("green_roof"), Some(Material::GREEN_ROOF));
+}
+
+#[test]
+fn rejects_out_of_range_materials() {
+    assert_eq!(
+        MaterialLibrary::from_toml("[mirror]\nemissivity = 1.2\nalbedo = 0.9"),
+        Err(Error::InvalidMaterial),
+    );
+    assert!(matches!(
+        MaterialLibrary::from_toml("[mirror]\nemissivity = 0.5"),
+        Err(Error::MaterialFile(_)),
+    ));
+}
+
+#[test]
+fn default_library_contains_builtin_materials() {
+    let library = MaterialLibrary::default();
+    assert_eq!(library.get("asphalt"), Some(Material::ASPHALT));
+    assert_eq!(library.get("cool_pavement"), Some(Material::COOL_PAVEMENT));
+    assert!(library
+        .iter()
+        .all(|(_, material)| material.validate().is_ok()));
+}
+
+#[test]
+fn rejects_sites_with_invalid_materials() {
+    let observation = Observation {
+        air_temperature: 20.0,
+        wind_speed: 2.0,
+        relative_humidity: 50.0,
+        binnenstad: false,
+        park: true,
+        shade: true,
+        timestamp: Utc.with_ymd_and_hms(2021, 7, 1, 12, 0, 0).unwrap(),
+    };
+    let mut site = Site::groningen(false, true);
+    site.surfaces.ground.emissivity = 1.5;
+
+    assert_eq!(
+        experienced_heat_stress_at_site(observation, &site),
+        Err(Error::InvalidMaterial),
+    );
+}
+
+#[test]
+fn library_materials_change_the_utci() {
+    let observation = Observation {
+        air_temperature: 28.0,
+        wind_speed: 2.0,
+        relative_humidity: 50.0,
+        binnenstad: false,
+        park: false,
+        shade: false,
+        timestamp: Utc.with_ymd_and_hms(2021, 7, 1, 12, 0, 0).unwrap(),
+    };
+    let mut library = MaterialLibrary::from_toml(
+        "[white_paving]\nemissivity = 0.9\nalbedo = 0.8",
+    )
+    .unwrap();
+    library.extend(MaterialLibrary::default());
+
+    let site = Site::groningen(false, false);
+    let paved = Site {
+        surfaces: Surfaces::from_library(
+            &library,
+            "klinkers",
+            "brick",
+            "tree_canopy",
+        )
+        .unwrap(),
+        ..site
+    };
+    let white = Site {
+        surfaces: Surfaces::from_library(
+            &library,
+            "white_paving",
+            "brick",
+            "tree_canopy",
+        )
+        .unwrap(),
+        ..site
+    };
+    assert_eq!(paved.surfaces, Surfaces::GRONINGEN_URBAN);
+
+    let paved = experienced_heat_stress_at_site(observation, &paved).unwrap();
+    let white = experienced_heat_stress_at_site(observation, &white).unwrap();
+    assert!((white.utci - paved.utci).abs() > 0.1);
+
+    assert_eq!(
+        Surfaces::from_library(&library, "marble", "brick", "tree_canopy"),
+        Err(Error::UnknownMaterial("marble".to_string())),
+    );
+}
@@ -0,0 +1,105 @@
+use chrono::{TimeZone, Utc};
+use utci::heat_indices::Hectopascal;
+use utci::{
+    experienced_pet, physiological_equivalent_temperature, Error, Observation,
+    PetPerson, Sex,
+};
+
+fn pet(
+    air_temperature: f32,
+    radiant_temperature: f32,
+    wind_speed: f32,
+    vapour_pressure: f64,
+) -> f32 {
+    physiological_equivalent_temperature(
+        air_temperature,
+        radiant_temperature,
+        wind_speed,
+        Hectopascal(vapour_pressure),
+        &PetPerson::REFERENCE,
+    )
+    .unwrap()
+}
+
+#[test]
+fn reference_room_is_air_temperature() {
+    for temperature in [10.0, 21.0, 35.0] {
+        let output = pet(temperature, temperature, 0.1, 12.0);
+        assert!((output - temperature).abs() < 0.1);
+    }
+}
+
+#[test]
+fn matches_hoppe_examples() {
+    // typical room
+    assert!((pet(21.0, 21.0, 0.1, 12.0) - 21.0).abs() < 0.5);
+    // summer in the sun and in the shade
+    assert!((pet(30.0, 60.0, 1.0, 21.0) - 43.0).abs() < 0.5);
+    assert!((pet(30.0, 30.0, 1.0, 21.0) - 29.0).abs() < 0.5);
+    // winter in the sun and in the shade
+    assert!((pet(-5.0, 40.0, 0.5, 2.0) - 10.0).abs() < 0.5);
+    assert!((pet(-5.0, -5.0, 5.0, 2.0) + 13.0).abs() < 0.5);
+}
+
+#[test]
+fn follows_radiation_and_wind() {
+    assert!(pet(25.0, 50.0, 1.0, 15.0) > pet(25.0, 25.0, 1.0, 15.0));
+    assert!(pet(10.0, 10.0, 5.0, 8.0) < pet(10.0, 10.0, 0.5, 8.0));
+}
+
+#[test]
+fn sun_in_groningen_raises_pet() {
+    let observation = Observation {
+        air_temperature: 27.0,
+        wind_speed: 3.0,
+        relative_humidity: 60.0,
+        binnenstad: true,
+        park: false,
+        shade: false,
+        timestamp: Utc.with_ymd_and_hms(2021, 7, 1, 12, 0, 0).unwrap(),
+    };
+    let noon = experienced_pet(observation, &PetPerson::REFERENCE).unwrap();
+    let night = experienced_pet(
+        Observation {
+            timestamp: Utc.with_ymd_and_hms(2021, 7, 1, 0, 0, 0).unwrap(),
+            ..observation
+        },
+        &PetPerson::default(),
+    )
+    .unwrap();
+    assert!(noon > 35.0 && night < 25.0);
+
+    let woman = PetPerson {
+        sex: Sex::Female,
+        clothing: 0.5,
+        ..PetPerson::REFERENCE
+    };
+    assert!(experienced_pet(observation, &woman).is_ok());
+}
+
+#[test]
+fn rejects_invalid_input() {
+    let invalid = |air_temperature, wind_speed, person: &PetPerson| {
+        physiological_equivalent_temperature(
+            air_temperature,
+            air_temperature,
+            wind_speed,
+            Hectopascal(12.0),
+            person,
+        )
+    };
+    let reference = PetPerson::REFERENCE;
+    assert_eq!(
+        invalid(60.0, 1.0, &reference),
+        Err(Error::InvalidAirTemperature)
+    );
+    assert_eq!(
+        invalid(20.0, 20.0, &reference),
+        Err(Error::InvalidWindSpeed)
+    );
+    let person = PetPerson {
+        height: 0.0,
+        ..reference
+    };
+    assert_eq!(invalid(20.0, 1.0, &person), Err(Error::InvalidProfile));
+}
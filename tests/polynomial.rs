@@ -0,0 +1,75 @@
+use std::convert::TryInto;
+use utci::heat_indices::{
+    utci_approx, utci_approx_lanes, utci_approx_slice, UTCI_COEFFICIENTS,
+    UTCI_LANES,
+};
+use utci::Error;
+
+fn inputs(len: usize) -> [Vec<f64>; 4] {
+    [
+        (0..len).map(|i| -20.0 + 6.0 * (i % 10) as f64).collect(),
+        (0..len).map(|i| 0.5 + (i % 7) as f64 * 2.0).collect(),
+        (0..len).map(|i| -10.0 + 5.0 * (i % 12) as f64).collect(),
+        (0..len).map(|i| 0.2 + 0.4 * (i % 9) as f64).collect(),
+    ]
+}
+
+// Sums every term of the coefficient table
+// on its own, as an oracle independent of
+// the Horner scheme
+fn naive_utci_approx(
+    air_temperature: f64,
+    wind_speed: f64,
+    temp_diff: f64,
+    vapour_pressure: f64,
+) -> f64 {
+    let inputs = [air_temperature, wind_speed, temp_diff, vapour_pressure];
+    let sum: f64 = UTCI_COEFFICIENTS
+        .iter()
+        .map(|(coefficient, powers)| {
+            powers
+                .iter()
+                .zip(inputs.iter())
+                .fold(*coefficient, |term, (power, input)| {
+                    term * input.powi(i32::from(*power))
+                })
+        })
+        .sum();
+    air_temperature + sum
+}
+
+#[test]
+fn lanes_match_naive_evaluation() {
+    let [ta, va, d_tmrt, pa] = inputs(UTCI_LANES);
+    let lanes = utci_approx_lanes::<UTCI_LANES>(
+        &ta[..].try_into().unwrap(),
+        &va[..].try_into().unwrap(),
+        &d_tmrt[..].try_into().unwrap(),
+        &pa[..].try_into().unwrap(),
+    );
+    for (index, value) in lanes.iter().enumerate() {
+        let naive =
+            naive_utci_approx(ta[index], va[index], d_tmrt[index], pa[index]);
+        assert!((value - naive).abs() < 1e-9 * naive.abs().max(1.0));
+    }
+}
+
+#[test]
+fn slice_matches_single_evaluation() {
+    // a length which leaves a partial group of lanes
+    let len = 10_003;
+    let [ta, va, d_tmrt, pa] = inputs(len);
+    let mut output = vec![0.0; len];
+    utci_approx_slice(&ta, &va, &d_tmrt, &pa, &mut output).unwrap();
+    for index in 0..len {
+        let single =
+            utci_approx(ta[index], va[index], d_tmrt[index], pa[index]);
+        assert_eq!(output[index], single);
+    }
+
+    let mut short = vec![0.0; len - 1];
+    assert_eq!(
+        utci_approx_slice(&ta, &va, &d_tmrt, &pa, &mut short),
+        Err(Error::InvalidInput),
+    );
+}
@@ -0,0 +1,115 @@
+use chrono::{TimeZone, Utc};
+use utci::{
+    experienced_heat_stress, experienced_personal_heat_stress,
+    personal_heat_stress, personal_heat_stress_with, AgeGroup, Error,
+    HeatStress, Observation, PersonalProfile, RiskHeuristic, RiskLevel,
+};
+
+fn observation() -> Observation {
+    Observation {
+        air_temperature: 27.0,
+        wind_speed: 2.0,
+        relative_humidity: 50.0,
+        binnenstad: true,
+        park: false,
+        shade: false,
+        timestamp: Utc.with_ymd_and_hms(2021, 7, 1, 12, 0, 0).unwrap(),
+    }
+}
+
+#[test]
+fn reference_person_follows_utci() {
+    let heat_stress = HeatStress {
+        utci: 30.0,
+        radiant_temperature: 45.0,
+    };
+    let personal =
+        personal_heat_stress(heat_stress, &PersonalProfile::REFERENCE).unwrap();
+    assert_eq!(personal.standard, heat_stress);
+    assert_eq!(personal.personal_temperature, 30.0);
+    assert_eq!(personal.risk, RiskLevel::Moderate);
+}
+
+#[test]
+fn keeps_standard_utci() {
+    let standard = experienced_heat_stress(observation()).unwrap();
+    let personal = experienced_personal_heat_stress(
+        observation(),
+        &PersonalProfile::ELDERLY,
+    )
+    .unwrap();
+    assert_eq!(personal.standard, standard);
+}
+
+#[test]
+fn vulnerable_people_reach_higher_risk() {
+    let heat_stress = HeatStress {
+        utci: 30.0,
+        radiant_temperature: 45.0,
+    };
+    let adult = PersonalProfile::REFERENCE;
+    let elderly = PersonalProfile {
+        age_group: AgeGroup::Elderly,
+        acclimatised: false,
+        ..adult
+    };
+    let adult = personal_heat_stress(heat_stress, &adult).unwrap();
+    let elderly = personal_heat_stress(heat_stress, &elderly).unwrap();
+    assert_eq!(elderly.personal_temperature, adult.personal_temperature);
+    assert!(elderly.risk > adult.risk);
+
+    // running in warm clothing adds heat
+    let runner = PersonalProfile {
+        clothing: 1.0,
+        metabolic_rate: 300.0,
+        ..PersonalProfile::REFERENCE
+    };
+    let runner = personal_heat_stress(heat_stress, &runner).unwrap();
+    assert!(runner.personal_temperature > 30.0);
+    assert!(runner.risk > RiskLevel::Moderate);
+}
+
+#[test]
+fn rejects_invalid_profile() {
+    let profile = PersonalProfile {
+        metabolic_rate: 20.0,
+        ..PersonalProfile::REFERENCE
+    };
+    assert_eq!(
+        experienced_personal_heat_stress(observation(), &profile),
+        Err(Error::InvalidProfile)
+    );
+    assert_eq!(RiskLevel::VeryHigh.to_string(), "very high");
+    assert_eq!(RiskLevel::ALL.len(), 5);
+}
+
+#[test]
+fn heuristic_can_be_overridden() {
+    let heat_stress = HeatStress {
+        utci: 30.0,
+        radiant_temperature: 45.0,
+    };
+    let profile = PersonalProfile::ELDERLY;
+    let default = personal_heat_stress(heat_stress, &profile).unwrap();
+    let same =
+        personal_heat_stress_with(heat_stress, &profile, &Default::default())
+            .unwrap();
+    assert_eq!(default, same);
+
+    let neutral = RiskHeuristic {
+        metabolic_sensitivity: 0.0,
+        clothing_sensitivity: 0.0,
+        acclimatisation_margin: 0.0,
+        child_margin: 0.0,
+        elderly_margin: 0.0,
+    };
+    let neutral =
+        personal_heat_stress_with(heat_stress, &profile, &neutral).unwrap();
+    assert_eq!(neutral.personal_temperature, 30.0);
+    assert_eq!(neutral.risk, RiskLevel::Moderate);
+
+    let heuristic: RiskHeuristic =
+        toml::from_str("elderly_margin = 10.0").unwrap();
+    assert_eq!(heuristic.elderly_margin, 10.0);
+    assert_eq!(heuristic.child_margin, RiskHeuristic::DEFAULT.child_margin);
+}
@@ -0,0 +1,97 @@
+#![cfg(feature = "python")]
+
+use std::sync::Once;
+
+use chrono::{TimeZone, Utc};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use utci as _;
+
+extern "C" {
+    fn PyInit_utci() -> *mut pyo3::ffi::PyObject;
+}
+
+static INIT: Once = Once::new();
+
+// Function to run a closure with the utci module
+// imported into an embedded interpreter
+fn with_module<F>(test: F)
+where
+    F: for<'py> FnOnce(Python<'py>, Bound<'py, PyModule>),
+{
+    INIT.call_once(|| {
+        unsafe {
+            pyo3::ffi::PyImport_AppendInittab(
+                b"utci\0".as_ptr().cast(),
+                Some(PyInit_utci),
+            );
+        }
+        Python::initialize();
+    });
+    Python::attach(|py| test(py, py.import("utci").unwrap()));
+}
+
+#[test]
+fn calculates_heat_stress() {
+    with_module(|py, module| {
+        let kwargs = PyDict::new(py);
+        kwargs.set_item("binnenstad", true).unwrap();
+        kwargs
+            .set_item(
+                "timestamp",
+                Utc.with_ymd_and_hms(2021, 7, 1, 12, 0, 0).unwrap(),
+            )
+            .unwrap();
+        let heat_stress = module
+            .getattr("experienced_heat_stress")
+            .unwrap()
+            .call((30.0, 2.0, 50.0), Some(&kwargs))
+            .unwrap();
+        let utci: f32 = heat_stress.getattr("utci").unwrap().extract().unwrap();
+        let radiant_temperature: f32 = heat_stress
+            .getattr("radiant_temperature")
+            .unwrap()
+            .extract()
+            .unwrap();
+        let expected = utci::experienced_heat_stress(utci::Observation {
+            air_temperature: 30.0,
+            wind_speed: 2.0,
+            relative_humidity: 50.0,
+            binnenstad: true,
+            park: false,
+            shade: false,
+            timestamp: Utc.with_ymd_and_hms(2021, 7, 1, 12, 0, 0).unwrap(),
+        })
+        .unwrap();
+        assert_eq!(utci, expected.utci);
+        assert_eq!(radiant_temperature, expected.radiant_temperature);
+    });
+}
+
+#[test]
+fn raises_value_error() {
+    with_module(|py, module| {
+        let error = module
+            .getattr("experienced_heat_stress")
+            .unwrap()
+            .call1((80.0, 2.0, 50.0))
+            .unwrap_err();
+        assert!(error.is_instance_of::<pyo3::exceptions::PyValueError>(py));
+    });
+}
+
+#[test]
+fn names_categories() {
+    with_module(|_, module| {
+        let stress_category = module.getattr("stress_category").unwrap();
+        let name: String =
+            stress_category.call1((30.0,)).unwrap().extract().unwrap();
+        assert_eq!(name, "moderate heat stress");
+        let names: Vec<String> = module
+            .getattr("STRESS_CATEGORIES")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(names.len(), utci::StressCategory::ALL.len());
+    });
+}
@@ -0,0 +1,70 @@
+"""Smoke tests of the Python module, run after `maturin develop`"""
+
+import numpy as np
+import pytest
+
+import utci
+
+
+def test_batch_accepts_strided_arrays():
+    air_temperature = np.array([20.0, 0.0, 25.0, 0.0], dtype=np.float32)
+    wind_speed = np.array([2.0, 0.0, 3.0, 0.0], dtype=np.float32)
+    relative_humidity = np.array([60.0, 0.0, 50.0, 0.0], dtype=np.float32)
+    timestamps = np.array(
+        ["2021-07-01T12:00", "2021-07-01T12:00"], dtype="datetime64[s]"
+    )
+
+    strided = utci.experienced_heat_stress_batch(
+        air_temperature[::2],
+        wind_speed[::2],
+        relative_humidity[::2],
+        timestamps,
+        [utci.Location(binnenstad=True)],
+    )
+    contiguous = utci.experienced_heat_stress_batch(
+        np.ascontiguousarray(air_temperature[::2]),
+        np.ascontiguousarray(wind_speed[::2]),
+        np.ascontiguousarray(relative_humidity[::2]),
+        timestamps,
+        [utci.Location(binnenstad=True)],
+    )
+    np.testing.assert_array_equal(strided, contiguous)
+
+
+def batch_at(location):
+    return utci.experienced_heat_stress_batch(
+        np.array([20.0, 25.0], dtype=np.float32),
+        np.array([2.0, 3.0], dtype=np.float32),
+        np.array([60.0, 50.0], dtype=np.float32),
+        np.array(["2021-07-01T12:00"] * 2, dtype="datetime64[s]"),
+        [utci.Location(), utci.Location(binnenstad=True)],
+        location,
+    )
+
+
+@pytest.mark.parametrize("dtype", [np.int64, np.int32, np.uint8, np.intp])
+def test_batch_accepts_integer_locations(dtype):
+    np.testing.assert_array_equal(
+        batch_at(np.array([1, 0], dtype=dtype)),
+        batch_at(np.array([1, 0], dtype=np.int64)),
+    )
+
+
+@pytest.mark.parametrize("location", [[0, -1], [0, 2], [0.0, 1.0]])
+def test_batch_rejects_invalid_locations(location):
+    with pytest.raises(ValueError):
+        batch_at(np.array(location))
+
+
+def test_utci_approx_accepts_strided_arrays():
+    values = np.array([[20.0, 2.0, 5.0, 1.5], [30.0, 1.0, 10.0, 2.0]])
+    strided = utci.utci_approx(*values.T)
+    contiguous = utci.utci_approx(*np.ascontiguousarray(values.T))
+    np.testing.assert_array_equal(strided, contiguous)
+    assert strided.shape == (2,)
+
+
+def test_categories():
+    assert utci.stress_category(30.0) == "moderate heat stress"
+    index = utci.stress_category_index(np.array([30.0], dtype=np.float32))
+    assert utci.STRESS_CATEGORIES[index[0]] == "moderate heat stress"
@@ -0,0 +1,75 @@
+use chrono::{TimeZone, Utc};
+use utci::radiation::{HUMAN_EMISSIVITY, STEFAN_BOLTZMANN};
+use utci::{
+    experienced_heat_stress, mean_radiant_temperature, Direction,
+    DirectionalFluxes, Error, Observation,
+};
+
+fn observation(hour: u32, shade: bool) -> Observation {
+    Observation {
+        air_temperature: 25.0,
+        wind_speed: 2.0,
+        relative_humidity: 50.0,
+        binnenstad: true,
+        park: false,
+        shade,
+        timestamp: Utc.with_ymd_and_hms(2021, 7, 1, hour, 0, 0).unwrap(),
+    }
+}
+
+#[test]
+fn weighting_factors_cover_the_body() {
+    let total: f32 = Direction::ALL
+        .iter()
+        .map(|direction| direction.weighting_factor())
+        .sum();
+    assert!((total - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn isothermal_enclosure_equals_air_temperature() {
+    let kelvin_temperature = 20f32 + 273.15;
+    let emission = STEFAN_BOLTZMANN * kelvin_temperature.powi(4);
+    let mut fluxes = DirectionalFluxes::default();
+    for direction in Direction::ALL.iter() {
+        fluxes.set(*direction, 0.0, emission);
+    }
+
+    let radiant_temperature = mean_radiant_temperature(&fluxes).unwrap();
+    assert!((radiant_temperature - 20.0).abs() < 0.01);
+    assert!((fluxes.absorbed() - HUMAN_EMISSIVITY * emission).abs() < 1e-3);
+}
+
+#[test]
+fn sunshine_raises_radiant_temperature() {
+    let mut fluxes = DirectionalFluxes::default();
+    for direction in Direction::ALL.iter() {
+        fluxes.set(*direction, 0.0, 420.0);
+    }
+    let shaded = mean_radiant_temperature(&fluxes).unwrap();
+    fluxes.set(Direction::South, 600.0, 420.0);
+    let sunlit = mean_radiant_temperature(&fluxes).unwrap();
+    assert!(sunlit > shaded + 10.0);
+}
+
+#[test]
+fn rejects_unphysical_fluxes() {
+    let mut fluxes = DirectionalFluxes::default();
+    fluxes.set(Direction::Up, -10.0, 400.0);
+    assert_eq!(
+        mean_radiant_temperature(&fluxes),
+        Err(Error::InvalidRadiantFlux),
+    );
+}
+
+#[test]
+fn realistic_summer_day() {
+    let sun = experienced_heat_stress(observation(12, false)).unwrap();
+    let shade = experienced_heat_stress(observation(12, true)).unwrap();
+    let night = experienced_heat_stress(observation(0, false)).unwrap();
+
+    assert!(sun.radiant_temperature > 45.0 && sun.radiant_temperature < 75.0);
+    assert!(shade.radiant_temperature < sun.radiant_temperature);
+    assert!(shade.utci < sun.utci);
+    assert!(night.radiant_temperature < 25.0);
+}
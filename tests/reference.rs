@@ -0,0 +1,221 @@
+//! Validation of the UTCI polynomial against
+//! published reference values
+
+use chrono::{TimeZone, Utc};
+use utci::heat_indices::{
+    calculate_polynomial_regression,
+    utci_approx,
+    vapour_pressure,
+    SaturationPhase,
+    VapourPressureFormula,
+    UTCI_COEFFICIENTS,
+};
+use utci::{experienced_heat_stress, Observation};
+
+const REFERENCE: &str = include_str!("data/utci_reference.csv");
+
+// Environment variable naming a copy of the full
+// official reference table in the same columns,
+// run instead of the embedded subsample
+const REFERENCE_TABLE: &str = "UTCI_REFERENCE_TABLE";
+
+struct Case {
+    air_temperature: f64,
+    radiant_temperature: f64,
+    wind_speed: f64,
+    relative_humidity: f64,
+    utci: f64,
+    tolerance: f64,
+}
+
+fn reference_cases() -> Vec<Case> {
+    let table = std::env::var(REFERENCE_TABLE)
+        .map(|path| std::fs::read_to_string(path).unwrap())
+        .unwrap_or_else(|_| REFERENCE.to_string());
+    table
+        .lines()
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let values: Vec<f64> = line
+                .split(',')
+                .map(|value| value.trim().parse().unwrap())
+                .collect();
+            Case {
+                air_temperature: values[0],
+                radiant_temperature: values[1],
+                wind_speed: values[2],
+                relative_humidity: values[3],
+                utci: values[4],
+                tolerance: values[5],
+            }
+        })
+        .collect()
+}
+
+// Runs every reference case through `utci` and
+// reports all cases outside of their tolerance
+fn check_reference<F>(utci: F)
+where
+    F: Fn(&Case) -> f64,
+{
+    let cases = reference_cases();
+    assert!(!cases.is_empty());
+
+    let failures: Vec<String> = cases
+        .iter()
+        .filter_map(|case| {
+            let output = utci(case);
+            let deviation = (output - case.utci).abs();
+            if deviation <= case.tolerance {
+                return None;
+            }
+            Some(format!(
+                "Ta={} Tmrt={} va={} RH={}: expected {} +/- {}, \
+                 got {:.3} (off by {:.3})",
+                case.air_temperature,
+                case.radiant_temperature,
+                case.wind_speed,
+                case.relative_humidity,
+                case.utci,
+                case.tolerance,
+                output,
+                deviation,
+            ))
+        })
+        .collect();
+
+    assert!(
+        failures.is_empty(),
+        "{} of {} reference cases failed:\n{}",
+        failures.len(),
+        cases.len(),
+        failures.join("\n"),
+    );
+}
+
+#[test]
+fn utci_approx_matches_reference() {
+    check_reference(|case| {
+        let vapour_pressure = vapour_pressure(
+            case.air_temperature,
+            case.relative_humidity,
+            VapourPressureFormula::Hardy,
+            SaturationPhase::Water,
+        )
+        .to_kilopascal();
+        utci_approx(
+            case.air_temperature,
+            case.wind_speed,
+            case.radiant_temperature - case.air_temperature,
+            vapour_pressure.0,
+        )
+    });
+}
+
+#[test]
+fn polynomial_regression_matches_reference() {
+    check_reference(|case| {
+        f64::from(calculate_polynomial_regression(
+            case.air_temperature as f32,
+            case.radiant_temperature as f32,
+            case.wind_speed as f32,
+            case.relative_humidity as f32,
+        ))
+    });
+}
+
+// Evaluates UTCI_approx as in the published Fortran,
+// summing every term on its own with the vapour
+// pressure of Hardy (1998), independent of the
+// crate's evaluation
+fn reference_utci(
+    air_temperature: f64,
+    radiant_temperature: f64,
+    wind_speed: f64,
+    relative_humidity: f64,
+) -> f64 {
+    let g = [
+        -2.8365744e3,
+        -6.028076559e3,
+        1.954263612e1,
+        -2.737830188e-2,
+        1.6261698e-5,
+        7.0229056e-10,
+        -1.8680009e-13,
+    ];
+    let kelvin_temperature = air_temperature + 273.15;
+    let mut saturation = 2.7150305 * kelvin_temperature.ln();
+    for (i, g) in g.iter().enumerate() {
+        saturation += g * kelvin_temperature.powi(i as i32 - 2);
+    }
+    let vapour_pressure =
+        saturation.exp() * 0.01 * relative_humidity / 100.0 / 10.0;
+
+    let inputs = [
+        air_temperature,
+        wind_speed,
+        radiant_temperature - air_temperature,
+        vapour_pressure,
+    ];
+    let mut utci = air_temperature;
+    for (coefficient, powers) in UTCI_COEFFICIENTS.iter() {
+        let mut term = *coefficient;
+        for (input, power) in inputs.iter().zip(powers.iter()) {
+            term *= input.powi(i32::from(*power));
+        }
+        utci += term;
+    }
+    utci
+}
+
+#[test]
+fn reference_evaluation_matches_reference() {
+    check_reference(|case| {
+        reference_utci(
+            case.air_temperature,
+            case.radiant_temperature,
+            case.wind_speed,
+            case.relative_humidity,
+        )
+    });
+}
+
+#[test]
+fn experienced_heat_stress_matches_reference_evaluation() {
+    // the public function agrees with the reference
+    // evaluation at its own radiant temperature
+    for hour in [0, 6, 12, 18] {
+        for air_temperature in [-20.0, 0.0, 15.0, 25.0, 35.0] {
+            for wind_speed in [0.5, 3.0, 12.0] {
+                for relative_humidity in [20.0, 60.0, 95.0] {
+                    let observation = Observation {
+                        air_temperature,
+                        wind_speed,
+                        relative_humidity,
+                        binnenstad: hour == 12,
+                        park: hour == 18,
+                        shade: hour == 6,
+                        timestamp: Utc
+                            .with_ymd_and_hms(2021, 7, 1, hour, 0, 0)
+                            .unwrap(),
+                    };
+                    let heat_stress =
+                        experienced_heat_stress(observation).unwrap();
+                    let utci = reference_utci(
+                        f64::from(air_temperature),
+                        f64::from(heat_stress.radiant_temperature),
+                        f64::from(wind_speed),
+                        f64::from(relative_humidity),
+                    );
+                    assert!(
+                        (f64::from(heat_stress.utci) - utci).abs() <= 0.1,
+                        "{:?}: expected {:.3}, got {}",
+                        observation,
+                        utci,
+                        heat_stress.utci,
+                    );
+                }
+            }
+        }
+    }
+}
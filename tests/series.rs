@@ -0,0 +1,119 @@
+use utci::series::{HumidityUnit, TemperatureUnit, WindSpeedUnit};
+use utci::{annotate_csv, read_observations, ColumnMapping, Error};
+
+const STATION: &str = include_str!("data/station.csv");
+
+fn mapping() -> ColumnMapping {
+    ColumnMapping::from_toml(
+        r#"
+        timestamp = "time"
+        air_temperature = "temp_f"
+        wind_speed = "wind_kmh"
+        relative_humidity = "rh"
+        timestamp_format = "%Y-%m-%d %H:%M"
+        temperature_unit = "fahrenheit"
+        wind_speed_unit = "kilometres_per_hour"
+        humidity_unit = "fraction"
+        delimiter = ";"
+        binnenstad = true
+        "#,
+    )
+    .unwrap()
+}
+
+#[test]
+fn converts_units() {
+    let mapping = mapping();
+    assert_eq!(mapping.temperature_unit, TemperatureUnit::Fahrenheit);
+    assert_eq!(mapping.wind_speed_unit, WindSpeedUnit::KilometresPerHour);
+    assert_eq!(mapping.humidity_unit, HumidityUnit::Fraction);
+
+    let observations = read_observations(STATION.as_bytes(), &mapping).unwrap();
+    let first = observations[0].as_ref().unwrap();
+    assert!((first.air_temperature - 25.0).abs() < 1e-4);
+    assert!((first.wind_speed - 2.0).abs() < 1e-4);
+    assert!((first.relative_humidity - 55.0).abs() < 1e-4);
+    assert!(first.binnenstad);
+    assert_eq!(first.timestamp.to_rfc3339(), "2021-07-01T10:00:00+00:00");
+}
+
+#[test]
+fn annotates_rows_and_reports_failures() {
+    let mut output = Vec::new();
+    let summary =
+        annotate_csv(STATION.as_bytes(), &mut output, &mapping()).unwrap();
+    assert_eq!(summary.rows, 5);
+
+    let failures: Vec<(u64, Error)> = summary
+        .failures
+        .into_iter()
+        .map(|failure| (failure.line, failure.error))
+        .collect();
+    assert_eq!(
+        failures,
+        vec![
+            (4, Error::MissingValue(String::from("temp_f"))),
+            (5, Error::InvalidAirTemperature),
+            (6, Error::InvalidTimestamp),
+        ],
+    );
+
+    let output = String::from_utf8(output).unwrap();
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(
+        lines[0],
+        "time;temp_f;rh;wind_kmh;utci;radiant_temperature;category;error",
+    );
+    let fields: Vec<&str> = lines[1].split(';').collect();
+    assert!(fields[4].parse::<f32>().is_ok());
+    assert!(fields[6].ends_with("stress"));
+    assert!(fields[7].is_empty());
+    assert!(lines[3]
+        .ends_with(&Error::MissingValue(String::from("temp_f")).to_string()));
+}
+
+#[test]
+fn unreadable_rows_are_failures() {
+    let mut station = STATION.as_bytes().to_vec();
+    station.extend_from_slice(b"\n2021-07-01 15:00;\xff;0.45;10.8\n");
+    station.extend_from_slice(b"2021-07-01 16:00;82.4;0.45;10.8\n");
+
+    let observations = read_observations(&station[..], &mapping()).unwrap();
+    assert_eq!(observations.len(), 7);
+    let failure = observations[5].as_ref().unwrap_err();
+    assert_eq!(failure.line, 7);
+    assert!(matches!(failure.error, Error::CsvFile(_)));
+    assert!(observations[6].is_ok());
+
+    let mut output = Vec::new();
+    let summary = annotate_csv(&station[..], &mut output, &mapping()).unwrap();
+    assert_eq!(summary.rows, 7);
+    assert_eq!(summary.failures[3].line, 7);
+    assert!(matches!(summary.failures[3].error, Error::CsvFile(_)));
+    let lines: Vec<&[u8]> = output.split(|&byte| byte == b'\n').collect();
+    assert!(lines[6].starts_with(b"2021-07-01 15:00;\xff;"));
+    assert!(String::from_utf8_lossy(lines[7]).contains("stress"));
+}
+
+#[test]
+fn missing_columns_are_errors() {
+    let result =
+        read_observations(STATION.as_bytes(), &ColumnMapping::default());
+    assert_eq!(result, Err(Error::MissingColumn(String::from("timestamp"))));
+}
+
+#[test]
+fn refuses_non_ascii_delimiter() {
+    let error = ColumnMapping::from_toml(r#"delimiter = "§""#).unwrap_err();
+    assert!(matches!(error, Error::CsvFile(_)));
+
+    let mapping = ColumnMapping {
+        delimiter: '€',
+        ..ColumnMapping::default()
+    };
+    let error = read_observations(STATION.as_bytes(), &mapping).unwrap_err();
+    assert!(matches!(error, Error::CsvFile(_)));
+    let error =
+        annotate_csv(STATION.as_bytes(), Vec::new(), &mapping).unwrap_err();
+    assert!(matches!(error, Error::CsvFile(_)));
+}
@@ -0,0 +1,137 @@
+#![cfg(feature = "server")]
+
+use std::io::{Read, Write};
+use std::net::{Shutdown, TcpListener, TcpStream};
+use std::process::{Child, Command, Stdio};
+use std::thread;
+use std::time::Duration;
+
+struct Service {
+    child: Child,
+    address: String,
+}
+
+impl Drop for Service {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+    }
+}
+
+// Starts the service on a free port, waiting
+// until it accepts connections
+fn start() -> Service {
+    let address = TcpListener::bind("127.0.0.1:0")
+        .and_then(|listener| listener.local_addr())
+        .unwrap()
+        .to_string();
+    let child = Command::new(env!("CARGO_BIN_EXE_utci-server"))
+        .args(["--address", &address])
+        .stderr(Stdio::null())
+        .spawn()
+        .unwrap();
+    let mut service = Service { child, address };
+    for _ in 0..100 {
+        if TcpStream::connect(&service.address).is_ok() {
+            return service;
+        }
+        let exited = service.child.try_wait().unwrap();
+        assert!(exited.is_none(), "utci-server exited with {:?}", exited);
+        thread::sleep(Duration::from_millis(50));
+    }
+    panic!("utci-server is not listening on {}", service.address);
+}
+
+// Sends a request, returning the status
+// code and the body of the response
+fn request(
+    service: &Service,
+    method: &str,
+    path: &str,
+    body: &str,
+) -> (u16, String) {
+    let mut stream = TcpStream::connect(&service.address).unwrap();
+    write!(
+        stream,
+        "{} {} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\
+         Content-Length: {}\r\n\r\n{}",
+        method,
+        path,
+        body.len(),
+        body,
+    )
+    .unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+
+    let status = response[9..12].parse().unwrap();
+    let body = response.split("\r\n\r\n").nth(1).unwrap().to_string();
+    (status, body)
+}
+
+#[test]
+fn serves_heat_stress() {
+    let service = start();
+
+    let (status, body) = request(
+        &service,
+        "POST",
+        "/utci",
+        r#"{"air_temperature": 25, "wind_speed": 2, "relative_humidity": 50,
+            "timestamp": "2021-07-01T12:00:00Z"}"#,
+    );
+    assert_eq!(status, 200);
+    let value: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert!(value["utci"].is_number());
+    assert!(value["radiant_temperature"].is_number());
+    assert!(value["category"].is_string());
+
+    let (status, body) = request(
+        &service,
+        "POST",
+        "/utci",
+        r#"{"air_temperature": 80, "wind_speed": 2, "relative_humidity": 50}"#,
+    );
+    assert_eq!(status, 400);
+    assert!(body.contains("Air Temperature"));
+
+    let (status, body) = request(
+        &service,
+        "POST",
+        "/utci",
+        r#"[{"air_temperature": 20, "wind_speed": 2, "relative_humidity": 50},
+            {"air_temperature": 80, "wind_speed": 2, "relative_humidity": 50}]"#,
+    );
+    assert_eq!(status, 200);
+    let values: Vec<serde_json::Value> = serde_json::from_str(&body).unwrap();
+    assert!(values[0]["utci"].is_number());
+    assert!(values[1]["error"].is_string());
+
+    let (status, body) = request(&service, "GET", "/categories", "");
+    assert_eq!(status, 200);
+    let values: Vec<serde_json::Value> = serde_json::from_str(&body).unwrap();
+    assert_eq!(values.len(), 10);
+
+    let (status, _) = request(&service, "GET", "/unknown", "");
+    assert_eq!(status, 404);
+}
+
+#[test]
+fn refuses_large_bodies() {
+    let service = start();
+
+    let mut stream = TcpStream::connect(&service.address).unwrap();
+    write!(
+        stream,
+        "POST /utci HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\
+         Content-Length: 2000000\r\n\r\n"
+    )
+    .unwrap();
+    stream.shutdown(Shutdown::Write).unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    assert_eq!(&response[9..12], "413");
+
+    let body = format!("[{}]", vec!["{}"; 400_000].join(","));
+    let (status, _) = request(&service, "POST", "/utci", &body);
+    assert_eq!(status, 413);
+}
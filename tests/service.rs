@@ -0,0 +1,27 @@
+use chrono::{TimeZone, Utc};
+use utci::{HeatStress, HeatStressOutput, ObservationInput};
+
+#[test]
+fn input_defaults_to_given_moment() {
+    let input: ObservationInput = serde_json::from_str(
+        r#"{"air_temperature": 25, "wind_speed": 2, "relative_humidity": 50}"#,
+    )
+    .unwrap();
+    let now = Utc.with_ymd_and_hms(2021, 7, 1, 12, 0, 0).unwrap();
+    let observation = input.observation(now);
+    assert_eq!(observation.timestamp, now);
+    assert!(!observation.binnenstad && !observation.park && !observation.shade);
+}
+
+#[test]
+fn output_is_flat() {
+    let output = HeatStressOutput::from(HeatStress {
+        utci: 30.0,
+        radiant_temperature: 45.0,
+    });
+    let value = serde_json::to_value(output).unwrap();
+    assert_eq!(value["utci"], 30.0);
+    assert_eq!(value["radiant_temperature"], 45.0);
+    assert_eq!(value["name"], "moderate heat stress");
+    assert!(value["colour"].is_string());
+}
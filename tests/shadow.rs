@@ -0,0 +1,97 @@
+use chrono::{TimeZone, Utc};
+use utci::{
+    hourly_shadow_masks, shadow_at, shadow_mask, Error, GeoTransform, Raster,
+    Shading, Site, SolarPosition,
+};
+
+// Function to describe a block of 20 m south
+// of an open square, with a tree of 8 m in
+// the square
+fn square() -> (Raster<f32>, Raster<f32>) {
+    let transform = GeoTransform {
+        origin_x: 233_000.0,
+        origin_y: 582_000.0,
+        pixel_width: 1.0,
+        pixel_height: 1.0,
+    };
+    let mut buildings = Raster::filled(11, 41, 0.0, transform);
+    for row in 30..41 {
+        for column in 0..11 {
+            buildings.set(column, row, 20.0);
+        }
+    }
+    let mut vegetation = Raster::filled(11, 41, 0.0, transform);
+    vegetation.set(2, 12, 8.0);
+    (buildings, vegetation)
+}
+
+fn noon() -> SolarPosition {
+    SolarPosition::at(
+        Utc.with_ymd_and_hms(2021, 7, 1, 11, 30, 0).unwrap(),
+        Site::GRONINGEN_LATITUDE,
+        Site::GRONINGEN_LONGITUDE,
+    )
+}
+
+#[test]
+fn casts_building_and_tree_shadows() {
+    let (buildings, vegetation) = square();
+    let position = noon();
+    let shadow = |column, row| {
+        shadow_at(&buildings, &vegetation, column, row, &position, 100.0)
+    };
+    assert_eq!(shadow(5, 25), Shading::Building);
+    assert_eq!(shadow(5, 2), Shading::Sunlit);
+    assert_eq!(shadow(2, 12), Shading::Tree);
+    assert_eq!(shadow(2, 9), Shading::Tree);
+    assert!(shadow(2, 9).is_tree_shade());
+
+    // beyond the search distance nothing is found
+    let far = shadow_at(&buildings, &vegetation, 5, 25, &position, 2.0);
+    assert_eq!(far, Shading::Sunlit);
+}
+
+#[test]
+fn locates_points() {
+    let (buildings, _) = square();
+    assert_eq!(buildings.pixel_at(233_005.5, 581_974.5), Some((5, 25)));
+    assert_eq!(buildings.pixel_at(232_999.0, 581_974.5), None);
+}
+
+#[test]
+fn masks_every_hour() {
+    let (buildings, vegetation) = square();
+    let start = Utc.with_ymd_and_hms(2021, 7, 1, 0, 0, 0).unwrap();
+    let masks = hourly_shadow_masks(
+        &buildings,
+        &vegetation,
+        start,
+        24,
+        Site::GRONINGEN_LATITUDE,
+        Site::GRONINGEN_LONGITUDE,
+        100.0,
+    )
+    .unwrap();
+    assert_eq!(masks.len(), 24);
+    assert_eq!(masks[13].timestamp, start + chrono::Duration::hours(13));
+    assert!(masks[0]
+        .shading
+        .data()
+        .iter()
+        .all(|code| *code == Shading::Night as u8));
+
+    let mask = shadow_mask(&buildings, &vegetation, &noon(), 100.0).unwrap();
+    assert!(mask.is_aligned(&buildings));
+    assert_eq!(mask.get(5, 25), Some(Shading::Building as u8));
+    assert_eq!(masks[11].shading.get(5, 2), Some(Shading::Sunlit as u8));
+}
+
+#[test]
+fn rejects_misaligned_rasters() {
+    let (buildings, _) = square();
+    let vegetation = Raster::filled(11, 41, 0.0, GeoTransform::default());
+    assert_eq!(
+        shadow_mask(&buildings, &vegetation, &noon(), 100.0),
+        Err(Error::InvalidInput)
+    );
+}
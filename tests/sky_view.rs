@@ -0,0 +1,107 @@
+use utci::{
+    sky_view_factor, sky_view_factor_raster, sky_view_factors, CanyonGeometry,
+    Error, GeoTransform, HorizonScan, Raster,
+};
+
+// Function to describe a long street running
+// north to south, 11 m wide between walls of
+// the given height
+fn street(building_height: f32) -> Raster<f32> {
+    let mut heights = Raster::filled(31, 401, 0.0, GeoTransform::default());
+    for row in 0..401 {
+        for column in (0..10).chain(21..31) {
+            heights.set(column, row, building_height);
+        }
+    }
+    heights
+}
+
+#[test]
+fn open_ground_sees_whole_sky() {
+    let heights = Raster::filled(9, 9, 0.0, GeoTransform::default());
+    let scan = HorizonScan::default();
+    assert_eq!(sky_view_factor(&heights, 4, 4, &scan), Ok(1.0));
+}
+
+#[test]
+fn long_street_matches_canyon() {
+    let scan = HorizonScan {
+        directions: 360,
+        search_distance: 200.0,
+    };
+    for building_height in [6.0, 12.0, 24.0].iter() {
+        let canyon = CanyonGeometry {
+            building_height: *building_height,
+            street_width: 11.0,
+            tree_height: 0.0,
+            orientation: 0.0,
+        };
+        let scanned =
+            sky_view_factor(&street(*building_height), 15, 200, &scan).unwrap();
+        assert!((scanned - canyon.sky_view_factor()).abs() < 0.01);
+    }
+}
+
+#[test]
+fn trees_hide_part_of_sky() {
+    let buildings = street(12.0);
+    let mut vegetation = Raster::filled(31, 401, 0.0, GeoTransform::default());
+    for row in 190..210 {
+        vegetation.set(17, row, 8.0);
+    }
+    let scan = HorizonScan::default();
+    let svf =
+        sky_view_factors(&buildings, &vegetation, 15, 200, &scan).unwrap();
+    assert!(svf.vegetation < 1.0);
+    assert!(svf.combined(0.1) < svf.buildings);
+    assert!(svf.combined(1.0) == svf.buildings);
+}
+
+#[test]
+fn maps_every_pixel() {
+    let heights = street(12.0);
+    let svf =
+        sky_view_factor_raster(&heights, &HorizonScan::default()).unwrap();
+    assert!(svf.is_aligned(&heights));
+    assert!(svf.get(15, 200).unwrap() < svf.get(0, 0).unwrap());
+
+    let scan = HorizonScan {
+        directions: 0,
+        ..HorizonScan::default()
+    };
+    assert_eq!(
+        sky_view_factor_raster(&heights, &scan),
+        Err(Error::InvalidInput)
+    );
+}
+
+#[test]
+fn point_scans_are_validated() {
+    let heights = street(12.0);
+    let vegetation = Raster::filled(31, 401, 0.0, GeoTransform::default());
+    let scan = HorizonScan {
+        directions: 0,
+        ..HorizonScan::default()
+    };
+    assert_eq!(
+        sky_view_factor(&heights, 15, 200, &scan),
+        Err(Error::InvalidInput)
+    );
+    assert_eq!(
+        sky_view_factors(&heights, &vegetation, 15, 200, &scan),
+        Err(Error::InvalidInput)
+    );
+
+    for pixel_size in [0.0, f64::NAN, f64::INFINITY].iter() {
+        let transform = GeoTransform {
+            pixel_width: *pixel_size,
+            pixel_height: *pixel_size,
+            ..GeoTransform::default()
+        };
+        let heights = Raster::filled(9, 9, 0.0, transform);
+        assert_eq!(
+            sky_view_factor(&heights, 4, 4, &HorizonScan::default()),
+            Err(Error::InvalidInput)
+        );
+    }
+}
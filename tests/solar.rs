@@ -0,0 +1,120 @@
+use chrono::{TimeZone, Utc};
+use utci::{
+    experienced_heat_stress_batch, CanyonGeometry, Location,
+    ObservationColumns, Site, SolarPosition, SolarRadiation,
+};
+
+#[test]
+fn summer_solstice_noon_over_groningen() {
+    // solar noon in Groningen is around 11:35 UTC
+    let timestamp = Utc.with_ymd_and_hms(2021, 6, 21, 11, 35, 0).unwrap();
+    let position = SolarPosition::at(
+        timestamp,
+        Site::GRONINGEN_LATITUDE,
+        Site::GRONINGEN_LONGITUDE,
+    );
+
+    assert!((position.declination - 23.44).abs() < 0.1);
+    assert!(position.hour_angle.abs() < 1.0);
+    assert!((position.zenith - (Site::GRONINGEN_LATITUDE - 23.44)).abs() < 0.3);
+    assert!((position.azimuth - 180.0).abs() < 2.0);
+}
+
+#[test]
+fn sun_rises_in_the_east() {
+    let timestamp = Utc.with_ymd_and_hms(2021, 3, 20, 7, 0, 0).unwrap();
+    let position = SolarPosition::at(
+        timestamp,
+        Site::GRONINGEN_LATITUDE,
+        Site::GRONINGEN_LONGITUDE,
+    );
+    assert!(position.is_daytime());
+    assert!(position.azimuth > 90.0 && position.azimuth < 135.0);
+}
+
+#[test]
+fn no_radiation_at_night() {
+    let timestamp = Utc.with_ymd_and_hms(2021, 7, 1, 0, 0, 0).unwrap();
+    let position = SolarPosition::at(
+        timestamp,
+        Site::GRONINGEN_LATITUDE,
+        Site::GRONINGEN_LONGITUDE,
+    );
+    let radiation = SolarRadiation::clear_sky(&position);
+
+    assert!(!position.is_daytime());
+    assert_eq!(radiation.global_horizontal(), 0.0);
+    assert!(!CanyonGeometry::GRONINGEN_BINNENSTAD.is_sunlit(&position));
+}
+
+#[test]
+fn splits_global_radiation() {
+    let timestamp = Utc.with_ymd_and_hms(2021, 7, 1, 12, 0, 0).unwrap();
+    let position = SolarPosition::at(
+        timestamp,
+        Site::GRONINGEN_LATITUDE,
+        Site::GRONINGEN_LONGITUDE,
+    );
+    let clear = SolarRadiation::clear_sky(&position);
+    let overcast = SolarRadiation::from_global_horizontal(150.0, &position);
+
+    assert!(clear.global_horizontal() > 700.0);
+    assert!(clear.direct_horizontal() > clear.diffuse_horizontal);
+    assert!((overcast.global_horizontal() - 150.0).abs() < 1e-9);
+    assert!(overcast.diffuse_horizontal > 0.9 * 150.0);
+}
+
+#[test]
+fn low_sun_is_blocked_across_the_street() {
+    let timestamp = Utc.with_ymd_and_hms(2021, 12, 21, 11, 30, 0).unwrap();
+    let position = SolarPosition::at(
+        timestamp,
+        Site::GRONINGEN_LATITUDE,
+        Site::GRONINGEN_LONGITUDE,
+    );
+    let east_west = CanyonGeometry {
+        orientation: 90.0,
+        ..CanyonGeometry::GRONINGEN_BINNENSTAD
+    };
+    let north_south = CanyonGeometry {
+        orientation: 0.0,
+        ..CanyonGeometry::GRONINGEN_BINNENSTAD
+    };
+    // the winter sun stands in the south, across an east-west street
+    assert!(!east_west.is_sunlit(&position));
+    assert!(north_south.is_sunlit(&position));
+}
+
+#[test]
+fn low_sun_keeps_a_physical_beam() {
+    // 1.5 degrees above the horizon at dawn
+    let timestamp = Utc.with_ymd_and_hms(2021, 7, 1, 3, 30, 0).unwrap();
+    let position = SolarPosition::at(
+        timestamp,
+        Site::GRONINGEN_LATITUDE,
+        Site::GRONINGEN_LONGITUDE,
+    );
+    assert!(position.elevation() > 0.5 && position.elevation() < 3.0);
+
+    let radiation = SolarRadiation::from_global_horizontal(60.0, &position);
+    assert!(radiation.direct_normal <= position.extraterrestrial);
+    assert!(radiation.diffuse_horizontal >= 0.0);
+    assert!((radiation.global_horizontal() - 60.0).abs() < 1e-9);
+
+    // the measured radiation gives a valid UTCI
+    let columns = ObservationColumns {
+        air_temperature: &[15.0],
+        wind_speed: &[2.0],
+        relative_humidity: &[80.0],
+        timestamp: &[timestamp],
+        location: &[],
+        global_radiation: &[60.0],
+    };
+    let mut output = [0.0];
+    let locations = [Location::groningen(false, false, false)];
+    let invalid =
+        experienced_heat_stress_batch(&columns, &locations, &mut output)
+            .unwrap();
+    assert_eq!(invalid, 0);
+    assert!(output[0].is_finite());
+}
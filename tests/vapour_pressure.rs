@@ -0,0 +1,79 @@
+use utci::heat_indices::{
+    saturation_vapour_pressure,
+    vapour_pressure,
+    Hectopascal,
+    Kilopascal,
+    SaturationPhase,
+    VapourPressureFormula,
+};
+
+const FORMULAS: [VapourPressureFormula; 4] = [
+    VapourPressureFormula::Hardy,
+    VapourPressureFormula::MagnusTetens,
+    VapourPressureFormula::Buck,
+    VapourPressureFormula::GoffGratch,
+];
+
+#[test]
+fn formulas_agree_over_water() {
+    // (air temperature, saturation vapour pressure [hPa])
+    let cases = [(-10.0, 2.865), (0.0, 6.112), (20.0, 23.39), (40.0, 73.84)];
+    for &(air_temperature, expected) in cases.iter() {
+        for &formula in FORMULAS.iter() {
+            let Hectopascal(pressure) = saturation_vapour_pressure(
+                air_temperature,
+                formula,
+                SaturationPhase::Water,
+            );
+            assert!(
+                (pressure - expected).abs() < 0.01 * expected,
+                "{:?} at {}: {}",
+                formula,
+                air_temperature,
+                pressure,
+            );
+        }
+    }
+}
+
+#[test]
+fn formulas_agree_over_ice() {
+    let cases = [(-30.0, 0.3801), (-10.0, 2.599), (0.0, 6.112)];
+    for &(air_temperature, expected) in cases.iter() {
+        for &formula in FORMULAS.iter() {
+            let Hectopascal(pressure) = saturation_vapour_pressure(
+                air_temperature,
+                formula,
+                SaturationPhase::Ice,
+            );
+            assert!(
+                (pressure - expected).abs() < 0.01 * expected,
+                "{:?} at {}: {}",
+                formula,
+                air_temperature,
+                pressure,
+            );
+        }
+    }
+}
+
+#[test]
+fn vapour_pressure_scales_with_humidity() {
+    let saturation = saturation_vapour_pressure(
+        25.0,
+        VapourPressureFormula::Hardy,
+        SaturationPhase::Water,
+    );
+    let pressure = vapour_pressure(
+        25.0,
+        50.0,
+        VapourPressureFormula::Hardy,
+        SaturationPhase::Water,
+    );
+    assert!((pressure.0 - saturation.0 / 2.0).abs() < 1e-12);
+
+    let Kilopascal(kilopascal) = pressure.to_kilopascal();
+    assert!((kilopascal - pressure.0 / 10.0).abs() < 1e-12);
+    let Hectopascal(hectopascal) = pressure.to_kilopascal().into();
+    assert!((hectopascal - pressure.0).abs() < 1e-12);
+}
@@ -0,0 +1,42 @@
+#![cfg(all(feature = "wasm", target_arch = "wasm32"))]
+
+use js_sys::{Reflect, JSON};
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+use utci::wasm::{experienced_heat_stress, stress_categories};
+
+// Function to read a property of a JavaScript object
+fn property(object: &JsValue, name: &str) -> JsValue {
+    Reflect::get(object, &JsValue::from_str(name)).unwrap()
+}
+
+#[wasm_bindgen_test]
+fn returns_plain_object() {
+    let input = JSON::parse(
+        r#"{
+            "airTemperature": 30.0,
+            "windSpeed": 2.0,
+            "relativeHumidity": 50.0,
+            "binnenstad": true,
+            "timestamp": "2021-07-01T12:00:00Z"
+        }"#,
+    )
+    .unwrap();
+    let result = experienced_heat_stress(input).unwrap();
+
+    let utci = property(&result, "utci").as_f64().unwrap();
+    assert!(utci > 30.0 && utci < 40.0);
+    assert!(property(&result, "radiant_temperature").as_f64().is_some());
+    assert_eq!(
+        property(&result, "name").as_string().unwrap(),
+        "strong heat stress"
+    );
+}
+
+#[wasm_bindgen_test]
+fn returns_legend_objects() {
+    let categories = stress_categories().unwrap();
+    let first = Reflect::get_u32(&categories, 0).unwrap();
+    assert!(property(&first, "colour").as_string().is_some());
+}